@@ -0,0 +1,173 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example.Gamut;
+
+public record Ref
+{
+    [JsonPropertyName("a")]
+    public string A { get; init; } = default!;
+}
+
+public record GamutDiscriminatorA : GamutDiscriminator
+{
+    [JsonPropertyName("a")]
+    public string A { get; init; } = default!;
+}
+
+public record GamutDiscriminatorB : GamutDiscriminator
+{
+    [JsonPropertyName("b")]
+    public string B { get; init; } = default!;
+}
+
+[JsonConverter(typeof(GamutDiscriminatorConverter))]
+public abstract record GamutDiscriminator;
+
+public class GamutDiscriminatorConverter : JsonConverter<GamutDiscriminator>
+{
+    public override GamutDiscriminator Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+    {
+        using var document = JsonDocument.ParseValue(ref reader);
+        var root = document.RootElement;
+        if (!root.TryGetProperty("tag", out var tag) || tag.ValueKind != JsonValueKind.String)
+        {
+            throw new JsonException("missing discriminator tag");
+        }
+
+        return tag.GetString() switch
+        {
+            "a" => root.Deserialize<GamutDiscriminatorA>(options)!,
+            "b" => root.Deserialize<GamutDiscriminatorB>(options)!,
+            var value => throw new JsonException($"unknown discriminator tag value: {value}"),
+        };
+    }
+
+    public override void Write(Utf8JsonWriter writer, GamutDiscriminator value, JsonSerializerOptions options)
+    {
+        var (tag, element) = value switch
+        {
+            GamutDiscriminatorA v => ("a", JsonSerializer.SerializeToElement(v, options)),
+            GamutDiscriminatorB v => ("b", JsonSerializer.SerializeToElement(v, options)),
+            _ => throw new JsonException("unknown discriminator variant"),
+        };
+
+        writer.WriteStartObject();
+        writer.WriteString("tag", tag);
+        foreach (var property in element.EnumerateObject())
+        {
+            property.WriteTo(writer);
+        }
+        writer.WriteEndObject();
+    }
+}
+
+public record GamutElements
+{
+    [JsonPropertyName("a")]
+    public string A { get; init; } = default!;
+}
+
+[JsonConverter(typeof(GamutEnumConverter))]
+public enum GamutEnum
+{
+    Bar,
+    Baz,
+    Foo,
+}
+
+public class GamutEnumConverter : JsonConverter<GamutEnum>
+{
+    public override GamutEnum Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+    {
+        return reader.GetString() switch
+        {
+            "BAR" => GamutEnum.Bar,
+            "BAZ" => GamutEnum.Baz,
+            "FOO" => GamutEnum.Foo,
+            var value => throw new JsonException($"unknown GamutEnum value: {value}"),
+        };
+    }
+
+    public override void Write(Utf8JsonWriter writer, GamutEnum value, JsonSerializerOptions options)
+    {
+        writer.WriteStringValue(value switch
+        {
+            GamutEnum.Bar => "BAR",
+            GamutEnum.Baz => "BAZ",
+            GamutEnum.Foo => "FOO",
+            _ => throw new JsonException($"unknown GamutEnum value: {value}"),
+        });
+    }
+}
+
+public record GamutType
+{
+    [JsonPropertyName("a")]
+    public bool A { get; init; } = default!;
+
+    [JsonPropertyName("b")]
+    public string B { get; init; } = default!;
+
+    [JsonPropertyName("c")]
+    public string C { get; init; } = default!;
+
+    [JsonPropertyName("d")]
+    public sbyte D { get; init; } = default!;
+
+    [JsonPropertyName("e")]
+    public byte E { get; init; } = default!;
+
+    [JsonPropertyName("f")]
+    public short F { get; init; } = default!;
+
+    [JsonPropertyName("g")]
+    public ushort G { get; init; } = default!;
+
+    [JsonPropertyName("h")]
+    public int H { get; init; } = default!;
+
+    [JsonPropertyName("i")]
+    public uint I { get; init; } = default!;
+
+    [JsonPropertyName("j")]
+    public float J { get; init; } = default!;
+
+    [JsonPropertyName("k")]
+    public double K { get; init; } = default!;
+}
+
+public record GamutValues
+{
+    [JsonPropertyName("a")]
+    public string A { get; init; } = default!;
+}
+
+public record Gamut
+{
+    [JsonPropertyName("discriminator")]
+    public GamutDiscriminator Discriminator { get; init; } = default!;
+
+    [JsonPropertyName("elements")]
+    public List<GamutElements> Elements { get; init; } = default!;
+
+    [JsonPropertyName("empty")]
+    public JsonElement Empty { get; init; } = default!;
+
+    [JsonPropertyName("enum")]
+    public GamutEnum Enum { get; init; } = default!;
+
+    [JsonPropertyName("ref")]
+    public Ref Ref { get; init; } = default!;
+
+    [JsonPropertyName("type")]
+    public GamutType Type { get; init; } = default!;
+
+    [JsonPropertyName("values")]
+    public Dictionary<string, GamutValues> Values { get; init; } = default!;
+}
+
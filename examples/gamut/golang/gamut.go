@@ -1,40 +1,136 @@
-package golang
-import "time"
-import "encoding/json"
-import "errors"
-var ErrUnknownVariant = errors.New("golang: unknown discriminator tag value")
-type Ref struct {
-	A string `json:"a"`
+// Code generated by jddf-codegen. DO NOT EDIT.
+//
+// Source: examples/gamut/gamut.jddf.json
+// Version: 0.1.0
+
+package gamut
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+)
+
+var ErrUnknownVariant = errors.New("gamut: unknown discriminator tag value")
+
+// marshalVariant encodes a discriminator's variant, along with its tag.
+func marshalVariant(tag, value string, variant interface{}) ([]byte, error) {
+	b, err := json.Marshal(variant)
+	if err != nil {
+		return nil, err
+	}
+
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return nil, err
+	}
+	if obj[tag], err = json.Marshal(value); err != nil {
+		return nil, err
+	}
+	return json.Marshal(obj)
 }
 
-type GamutType struct {
-	I uint32 `json:"i"`
-	B string `json:"b"`
-	C string `json:"c"`
-	H int32 `json:"h"`
-	J float32 `json:"j"`
-	F int16 `json:"f"`
-	D int8 `json:"d"`
-	A bool `json:"a"`
-	G uint16 `json:"g"`
-	K float64 `json:"k"`
-	E uint8 `json:"e"`
+// InvalidEnumValueError is returned when encoding or decoding a value
+// which an enum does not allow.
+type InvalidEnumValueError struct {
+	Type  string
+	Value string
 }
 
-type GamutEnum = string
+func (e *InvalidEnumValueError) Error() string {
+	return fmt.Sprintf("gamut: invalid %s value: %q", e.Type, e.Value)
+}
 
-const GamutEnumBAR GamutEnum = "BAR"
+// ValidationError is a way in which a value does not satisfy the schema.
+// Both paths are JSON Pointers: InstancePath to the part of the value at
+// fault, and SchemaPath to the part of the schema it does not satisfy.
+type ValidationError struct {
+	InstancePath string
+	SchemaPath   string
+}
 
-const GamutEnumFOO GamutEnum = "FOO"
+func (e ValidationError) Error() string {
+	return fmt.Sprintf("gamut: value at %q does not satisfy schema at %q", e.InstancePath, e.SchemaPath)
+}
 
-const GamutEnumBAZ GamutEnum = "BAZ"
+// ValidationErrors is every way in which a value does not satisfy the schema.
+//
+// A struct checks the integers it holds as it is decoded, and reports any
+// which are out of range, or are not integers, among these. An integer
+// held by no struct is decoded by encoding/json alone, which fails to
+// decode it in those cases, and also where it is written as JDDF allows
+// but encoding/json does not, such as 1.0.
+type ValidationErrors []ValidationError
 
-type GamutValues struct {
-	A string `json:"a"`
+func (e ValidationErrors) Error() string {
+	msgs := make([]string, len(e))
+	for i, err := range e {
+		msgs[i] = err.Error()
+	}
+	return strings.Join(msgs, "; ")
 }
 
-type GamutElements struct {
+// pointerEscaper escapes an object's key for use in a JSON Pointer.
+var pointerEscaper = strings.NewReplacer("~", "~0", "/", "~1")
+
+// decodeInteger returns the integer a JSON value is, if it is one within
+// [min, max]. Unlike encoding/json, JDDF allows an integer to be written
+// with a fractional part or exponent, such as 1.0 or 1e2.
+func decodeInteger(b json.RawMessage, min, max float64) (int64, bool) {
+	f, err := strconv.ParseFloat(string(b), 64)
+	if err != nil || f < min || f > max || f != float64(int64(f)) {
+		return 0, false
+	}
+	return int64(f), true
+}
+
+type Ref struct {
 	A string `json:"a"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+}
+
+func (v *Ref) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "a":
+		default:
+			return fmt.Errorf("gamut: unknown property %q in Ref", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["a"]; !ok {
+		v.missing = append(v.missing, "/properties/a")
+	}
+
+	type alias Ref
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v Ref) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v Ref) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/definitions/ref" + missing})
+	}
 }
 
 type GamutDiscriminatorTag = string
@@ -52,17 +148,22 @@ type GamutDiscriminator struct {
 func (v GamutDiscriminator) MarshalJSON() ([]byte, error) {
 	switch v.Tag {
 	case "a":
-		return json.Marshal(struct { Tag string `json:"tag"`; GamutDiscriminatorA }{ Tag: "a", GamutDiscriminatorA: v.GamutDiscriminatorA });
+		return marshalVariant("tag", "a", &v.GamutDiscriminatorA)
 	case "b":
-		return json.Marshal(struct { Tag string `json:"tag"`; GamutDiscriminatorB }{ Tag: "b", GamutDiscriminatorB: v.GamutDiscriminatorB });
+		return marshalVariant("tag", "b", &v.GamutDiscriminatorB)
 	}
 	return nil, ErrUnknownVariant
 }
+
 func (v *GamutDiscriminator) UnmarshalJSON(b []byte) error {
 	var obj map[string]interface{}
-	if err := json.Unmarshal(b, &obj); err != nil { return err }
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
 	tag, ok := obj["tag"].(string)
-	if !ok { return ErrUnknownVariant }
+	if !ok {
+		return ErrUnknownVariant
+	}
 	v.Tag = tag
 	switch tag {
 	case "a":
@@ -72,20 +173,499 @@ func (v *GamutDiscriminator) UnmarshalJSON(b []byte) error {
 	}
 	return ErrUnknownVariant
 }
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v GamutDiscriminator) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v GamutDiscriminator) validate(path string, errs *ValidationErrors) {
+	switch v.Tag {
+	case "a":
+		v.GamutDiscriminatorA.validate(path, errs)
+	case "b":
+		v.GamutDiscriminatorB.validate(path, errs)
+	default:
+		*errs = append(*errs, ValidationError{InstancePath: path + "/tag", SchemaPath: "/properties/discriminator/discriminator/mapping"})
+	}
+}
+
 type GamutDiscriminatorA struct {
 	A string `json:"a"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+}
+
+func (v *GamutDiscriminatorA) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "tag", "a":
+		default:
+			return fmt.Errorf("gamut: unknown property %q in GamutDiscriminatorA", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["a"]; !ok {
+		v.missing = append(v.missing, "/properties/a")
+	}
+
+	type alias GamutDiscriminatorA
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v GamutDiscriminatorA) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
 }
+
+func (v GamutDiscriminatorA) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/properties/discriminator/discriminator/mapping/a" + missing})
+	}
+}
+
 type GamutDiscriminatorB struct {
 	B string `json:"b"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+}
+
+func (v *GamutDiscriminatorB) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "tag", "b":
+		default:
+			return fmt.Errorf("gamut: unknown property %q in GamutDiscriminatorB", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["b"]; !ok {
+		v.missing = append(v.missing, "/properties/b")
+	}
+
+	type alias GamutDiscriminatorB
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v GamutDiscriminatorB) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v GamutDiscriminatorB) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/properties/discriminator/discriminator/mapping/b" + missing})
+	}
+}
+
+type GamutElements struct {
+	A string `json:"a"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+}
+
+func (v *GamutElements) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "a":
+		default:
+			return fmt.Errorf("gamut: unknown property %q in GamutElements", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["a"]; !ok {
+		v.missing = append(v.missing, "/properties/a")
+	}
+
+	type alias GamutElements
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v GamutElements) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v GamutElements) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/properties/elements/elements" + missing})
+	}
+}
+
+type GamutEnum string
+
+const GamutEnumBAR GamutEnum = "BAR"
+
+const GamutEnumBAZ GamutEnum = "BAZ"
+
+const GamutEnumFOO GamutEnum = "FOO"
+
+// AllGamutEnumValues is every value GamutEnum can take on.
+var AllGamutEnumValues = []GamutEnum{
+	GamutEnumBAR,
+	GamutEnumBAZ,
+	GamutEnumFOO,
+}
+
+func (v GamutEnum) String() string {
+	return string(v)
+}
+
+// IsValid reports whether v is one of AllGamutEnumValues.
+func (v GamutEnum) IsValid() bool {
+	switch v {
+	case GamutEnumBAR, GamutEnumBAZ, GamutEnumFOO:
+		return true
+	}
+	return false
+}
+
+func (v GamutEnum) MarshalJSON() ([]byte, error) {
+	if !v.IsValid() {
+		return nil, &InvalidEnumValueError{Type: "GamutEnum", Value: string(v)}
+	}
+	return json.Marshal(string(v))
+}
+
+func (v *GamutEnum) UnmarshalJSON(b []byte) error {
+	var s string
+	if err := json.Unmarshal(b, &s); err != nil {
+		return err
+	}
+	if !GamutEnum(s).IsValid() {
+		return &InvalidEnumValueError{Type: "GamutEnum", Value: s}
+	}
+	*v = GamutEnum(s)
+	return nil
+}
+
+func (v GamutEnum) validate(path string, errs *ValidationErrors) {
+	if !v.IsValid() {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/properties/enum/enum"})
+	}
+}
+
+type GamutType struct {
+	A bool    `json:"a"`
+	B string  `json:"b"`
+	C string  `json:"c"`
+	D int8    `json:"d"`
+	E uint8   `json:"e"`
+	F int16   `json:"f"`
+	G uint16  `json:"g"`
+	H int32   `json:"h"`
+	I uint32  `json:"i"`
+	J float32 `json:"j"`
+	K float64 `json:"k"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+
+	// the ways in which the integers in the JSON it was decoded from did not
+	// satisfy the schema, with instance paths relative to the struct's own
+	invalid []ValidationError
+}
+
+func (v *GamutType) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k":
+		default:
+			return fmt.Errorf("gamut: unknown property %q in GamutType", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["a"]; !ok {
+		v.missing = append(v.missing, "/properties/a")
+	}
+	if _, ok := obj["b"]; !ok {
+		v.missing = append(v.missing, "/properties/b")
+	}
+	if _, ok := obj["c"]; !ok {
+		v.missing = append(v.missing, "/properties/c")
+	}
+	if _, ok := obj["d"]; !ok {
+		v.missing = append(v.missing, "/properties/d")
+	}
+	if _, ok := obj["e"]; !ok {
+		v.missing = append(v.missing, "/properties/e")
+	}
+	if _, ok := obj["f"]; !ok {
+		v.missing = append(v.missing, "/properties/f")
+	}
+	if _, ok := obj["g"]; !ok {
+		v.missing = append(v.missing, "/properties/g")
+	}
+	if _, ok := obj["h"]; !ok {
+		v.missing = append(v.missing, "/properties/h")
+	}
+	if _, ok := obj["i"]; !ok {
+		v.missing = append(v.missing, "/properties/i")
+	}
+	if _, ok := obj["j"]; !ok {
+		v.missing = append(v.missing, "/properties/j")
+	}
+	if _, ok := obj["k"]; !ok {
+		v.missing = append(v.missing, "/properties/k")
+	}
+
+	type alias GamutType
+	var numbers struct {
+		alias
+		D json.RawMessage `json:"d"`
+		E json.RawMessage `json:"e"`
+		F json.RawMessage `json:"f"`
+		G json.RawMessage `json:"g"`
+		H json.RawMessage `json:"h"`
+		I json.RawMessage `json:"i"`
+	}
+	numbers.alias = alias(*v)
+	if err := json.Unmarshal(b, &numbers); err != nil {
+		return err
+	}
+	*v = GamutType(numbers.alias)
+
+	v.invalid = nil
+	if _, ok := obj["d"]; ok {
+		if n, ok := decodeInteger(numbers.D, -128, 127); ok {
+			v.D = int8(n)
+		} else {
+			v.invalid = append(v.invalid, ValidationError{InstancePath: "/d", SchemaPath: "/properties/type/properties/d/type"})
+		}
+	}
+	if _, ok := obj["e"]; ok {
+		if n, ok := decodeInteger(numbers.E, 0, 255); ok {
+			v.E = uint8(n)
+		} else {
+			v.invalid = append(v.invalid, ValidationError{InstancePath: "/e", SchemaPath: "/properties/type/properties/e/type"})
+		}
+	}
+	if _, ok := obj["f"]; ok {
+		if n, ok := decodeInteger(numbers.F, -32768, 32767); ok {
+			v.F = int16(n)
+		} else {
+			v.invalid = append(v.invalid, ValidationError{InstancePath: "/f", SchemaPath: "/properties/type/properties/f/type"})
+		}
+	}
+	if _, ok := obj["g"]; ok {
+		if n, ok := decodeInteger(numbers.G, 0, 65535); ok {
+			v.G = uint16(n)
+		} else {
+			v.invalid = append(v.invalid, ValidationError{InstancePath: "/g", SchemaPath: "/properties/type/properties/g/type"})
+		}
+	}
+	if _, ok := obj["h"]; ok {
+		if n, ok := decodeInteger(numbers.H, -2147483648, 2147483647); ok {
+			v.H = int32(n)
+		} else {
+			v.invalid = append(v.invalid, ValidationError{InstancePath: "/h", SchemaPath: "/properties/type/properties/h/type"})
+		}
+	}
+	if _, ok := obj["i"]; ok {
+		if n, ok := decodeInteger(numbers.I, 0, 4294967295); ok {
+			v.I = uint32(n)
+		} else {
+			v.invalid = append(v.invalid, ValidationError{InstancePath: "/i", SchemaPath: "/properties/type/properties/i/type"})
+		}
+	}
+	return nil
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v GamutType) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v GamutType) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/properties/type" + missing})
+	}
+	for _, invalid := range v.invalid {
+		*errs = append(*errs, ValidationError{InstancePath: path + invalid.InstancePath, SchemaPath: invalid.SchemaPath})
+	}
+}
+
+type GamutValues struct {
+	A string `json:"a"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+}
+
+func (v *GamutValues) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "a":
+		default:
+			return fmt.Errorf("gamut: unknown property %q in GamutValues", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["a"]; !ok {
+		v.missing = append(v.missing, "/properties/a")
+	}
+
+	type alias GamutValues
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v GamutValues) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v GamutValues) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/properties/values/values" + missing})
+	}
 }
 
 type Gamut struct {
-	Ref Ref `json:"ref"`
-	Type GamutType `json:"type"`
-	Enum GamutEnum `json:"enum"`
-	Values map[string]GamutValues `json:"values"`
-	Empty interface{} `json:"empty"`
-	Elements []GamutElements `json:"elements"`
-	Discriminator GamutDiscriminator `json:"discriminator"`
+	Discriminator GamutDiscriminator     `json:"discriminator"`
+	Elements      []GamutElements        `json:"elements"`
+	Empty         interface{}            `json:"empty"`
+	Enum          GamutEnum              `json:"enum"`
+	Ref           Ref                    `json:"ref"`
+	Type          GamutType              `json:"type"`
+	Values        map[string]GamutValues `json:"values"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
 }
 
+func (v *Gamut) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "discriminator", "elements", "empty", "enum", "ref", "type", "values":
+		default:
+			return fmt.Errorf("gamut: unknown property %q in Gamut", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["discriminator"]; !ok {
+		v.missing = append(v.missing, "/properties/discriminator")
+	}
+	if _, ok := obj["elements"]; !ok {
+		v.missing = append(v.missing, "/properties/elements")
+	}
+	if _, ok := obj["empty"]; !ok {
+		v.missing = append(v.missing, "/properties/empty")
+	}
+	if _, ok := obj["enum"]; !ok {
+		v.missing = append(v.missing, "/properties/enum")
+	}
+	if _, ok := obj["ref"]; !ok {
+		v.missing = append(v.missing, "/properties/ref")
+	}
+	if _, ok := obj["type"]; !ok {
+		v.missing = append(v.missing, "/properties/type")
+	}
+	if _, ok := obj["values"]; !ok {
+		v.missing = append(v.missing, "/properties/values")
+	}
+
+	type alias Gamut
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v Gamut) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v Gamut) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: missing})
+	}
+	v.Discriminator.validate(path+"/discriminator", errs)
+	for i, x := range v.Elements {
+		x.validate(path+"/elements/"+fmt.Sprint(i), errs)
+	}
+	v.Enum.validate(path+"/enum", errs)
+	v.Ref.validate(path+"/ref", errs)
+	v.Type.validate(path+"/type", errs)
+	for k, x := range v.Values {
+		x.validate(path+"/values/"+pointerEscaper.Replace(k), errs)
+	}
+}
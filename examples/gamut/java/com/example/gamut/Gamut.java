@@ -0,0 +1,87 @@
+package com.example.gamut;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.List;
+import java.util.Map;
+
+public class Gamut {
+    @JsonProperty("discriminator")
+    private GamutDiscriminator discriminator;
+
+    @JsonProperty("elements")
+    private List<GamutElements> elements;
+
+    @JsonProperty("empty")
+    private Object empty;
+
+    @JsonProperty("enum")
+    private GamutEnum enum_;
+
+    @JsonProperty("ref")
+    private Ref ref;
+
+    @JsonProperty("type")
+    private GamutType type;
+
+    @JsonProperty("values")
+    private Map<String, GamutValues> values;
+
+    public Gamut() {
+    }
+
+    public GamutDiscriminator getDiscriminator() {
+        return discriminator;
+    }
+
+    public void setDiscriminator(GamutDiscriminator discriminator) {
+        this.discriminator = discriminator;
+    }
+
+    public List<GamutElements> getElements() {
+        return elements;
+    }
+
+    public void setElements(List<GamutElements> elements) {
+        this.elements = elements;
+    }
+
+    public Object getEmpty() {
+        return empty;
+    }
+
+    public void setEmpty(Object empty) {
+        this.empty = empty;
+    }
+
+    public GamutEnum getEnum_() {
+        return enum_;
+    }
+
+    public void setEnum_(GamutEnum enum_) {
+        this.enum_ = enum_;
+    }
+
+    public Ref getRef() {
+        return ref;
+    }
+
+    public void setRef(Ref ref) {
+        this.ref = ref;
+    }
+
+    public GamutType getType() {
+        return type;
+    }
+
+    public void setType(GamutType type) {
+        this.type = type;
+    }
+
+    public Map<String, GamutValues> getValues() {
+        return values;
+    }
+
+    public void setValues(Map<String, GamutValues> values) {
+        this.values = values;
+    }
+}
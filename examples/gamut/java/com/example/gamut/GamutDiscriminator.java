@@ -0,0 +1,12 @@
+package com.example.gamut;
+
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "tag")
+@JsonSubTypes({
+    @JsonSubTypes.Type(name = "a", value = GamutDiscriminatorA.class),
+    @JsonSubTypes.Type(name = "b", value = GamutDiscriminatorB.class),
+})
+public abstract class GamutDiscriminator {
+}
@@ -0,0 +1,19 @@
+package com.example.gamut;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public class GamutDiscriminatorA extends GamutDiscriminator {
+    @JsonProperty("a")
+    private String a;
+
+    public GamutDiscriminatorA() {
+    }
+
+    public String getA() {
+        return a;
+    }
+
+    public void setA(String a) {
+        this.a = a;
+    }
+}
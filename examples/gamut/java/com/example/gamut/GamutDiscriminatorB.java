@@ -0,0 +1,19 @@
+package com.example.gamut;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public class GamutDiscriminatorB extends GamutDiscriminator {
+    @JsonProperty("b")
+    private String b;
+
+    public GamutDiscriminatorB() {
+    }
+
+    public String getB() {
+        return b;
+    }
+
+    public void setB(String b) {
+        this.b = b;
+    }
+}
@@ -0,0 +1,19 @@
+package com.example.gamut;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public class GamutElements {
+    @JsonProperty("a")
+    private String a;
+
+    public GamutElements() {
+    }
+
+    public String getA() {
+        return a;
+    }
+
+    public void setA(String a) {
+        this.a = a;
+    }
+}
@@ -0,0 +1,14 @@
+package com.example.gamut;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public enum GamutEnum {
+    @JsonProperty("BAR")
+    BAR,
+
+    @JsonProperty("BAZ")
+    BAZ,
+
+    @JsonProperty("FOO")
+    FOO,
+}
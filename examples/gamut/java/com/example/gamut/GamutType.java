@@ -0,0 +1,129 @@
+package com.example.gamut;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public class GamutType {
+    @JsonProperty("a")
+    private Boolean a;
+
+    @JsonProperty("b")
+    private String b;
+
+    @JsonProperty("c")
+    private String c;
+
+    @JsonProperty("d")
+    private Byte d;
+
+    @JsonProperty("e")
+    private Short e;
+
+    @JsonProperty("f")
+    private Short f;
+
+    @JsonProperty("g")
+    private Integer g;
+
+    @JsonProperty("h")
+    private Integer h;
+
+    @JsonProperty("i")
+    private Long i;
+
+    @JsonProperty("j")
+    private Float j;
+
+    @JsonProperty("k")
+    private Double k;
+
+    public GamutType() {
+    }
+
+    public Boolean getA() {
+        return a;
+    }
+
+    public void setA(Boolean a) {
+        this.a = a;
+    }
+
+    public String getB() {
+        return b;
+    }
+
+    public void setB(String b) {
+        this.b = b;
+    }
+
+    public String getC() {
+        return c;
+    }
+
+    public void setC(String c) {
+        this.c = c;
+    }
+
+    public Byte getD() {
+        return d;
+    }
+
+    public void setD(Byte d) {
+        this.d = d;
+    }
+
+    public Short getE() {
+        return e;
+    }
+
+    public void setE(Short e) {
+        this.e = e;
+    }
+
+    public Short getF() {
+        return f;
+    }
+
+    public void setF(Short f) {
+        this.f = f;
+    }
+
+    public Integer getG() {
+        return g;
+    }
+
+    public void setG(Integer g) {
+        this.g = g;
+    }
+
+    public Integer getH() {
+        return h;
+    }
+
+    public void setH(Integer h) {
+        this.h = h;
+    }
+
+    public Long getI() {
+        return i;
+    }
+
+    public void setI(Long i) {
+        this.i = i;
+    }
+
+    public Float getJ() {
+        return j;
+    }
+
+    public void setJ(Float j) {
+        this.j = j;
+    }
+
+    public Double getK() {
+        return k;
+    }
+
+    public void setK(Double k) {
+        this.k = k;
+    }
+}
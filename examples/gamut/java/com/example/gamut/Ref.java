@@ -0,0 +1,19 @@
+package com.example.gamut;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public class Ref {
+    @JsonProperty("a")
+    private String a;
+
+    public Ref() {
+    }
+
+    public String getA() {
+        return a;
+    }
+
+    public void setA(String a) {
+        this.a = a;
+    }
+}
@@ -0,0 +1,99 @@
+package com.example.gamut
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonClassDiscriminator
+import kotlinx.serialization.json.JsonElement
+
+@Serializable
+data class Ref(
+    @SerialName("a")
+    val a: String,
+)
+
+@Serializable
+@SerialName("a")
+data class GamutDiscriminatorA(
+    @SerialName("a")
+    val a: String,
+) : GamutDiscriminator()
+
+@Serializable
+@SerialName("b")
+data class GamutDiscriminatorB(
+    @SerialName("b")
+    val b: String,
+) : GamutDiscriminator()
+
+@OptIn(ExperimentalSerializationApi::class)
+@Serializable
+@JsonClassDiscriminator("tag")
+sealed class GamutDiscriminator
+
+@Serializable
+data class GamutElements(
+    @SerialName("a")
+    val a: String,
+)
+
+@Serializable
+enum class GamutEnum {
+    @SerialName("BAR")
+    BAR,
+    @SerialName("BAZ")
+    BAZ,
+    @SerialName("FOO")
+    FOO,
+}
+
+@Serializable
+data class GamutType(
+    @SerialName("a")
+    val a: Boolean,
+    @SerialName("b")
+    val b: String,
+    @SerialName("c")
+    val c: String,
+    @SerialName("d")
+    val d: Byte,
+    @SerialName("e")
+    val e: Short,
+    @SerialName("f")
+    val f: Short,
+    @SerialName("g")
+    val g: Int,
+    @SerialName("h")
+    val h: Int,
+    @SerialName("i")
+    val i: Long,
+    @SerialName("j")
+    val j: Float,
+    @SerialName("k")
+    val k: Double,
+)
+
+@Serializable
+data class GamutValues(
+    @SerialName("a")
+    val a: String,
+)
+
+@Serializable
+data class Gamut(
+    @SerialName("discriminator")
+    val discriminator: GamutDiscriminator,
+    @SerialName("elements")
+    val elements: List<GamutElements>,
+    @SerialName("empty")
+    val empty: JsonElement,
+    @SerialName("enum")
+    val enum: GamutEnum,
+    @SerialName("ref")
+    val ref: Ref,
+    @SerialName("type")
+    val type: GamutType,
+    @SerialName("values")
+    val values: Map<String, GamutValues>,
+)
+
@@ -0,0 +1,202 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from datetime import datetime
+from enum import Enum
+from typing import Any, Dict, List, Optional
+
+
+def _parse_timestamp(value: str) -> datetime:
+    # datetime.fromisoformat does not understand the "Z" suffix.
+    return datetime.fromisoformat(value.replace("Z", "+00:00"))
+
+
+@dataclass
+class Ref:
+    a: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Ref:
+        return cls(
+            a=data["a"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["a"] = self.a
+        return data
+
+
+class GamutDiscriminator:
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> GamutDiscriminator:
+        tag = data["tag"]
+        if tag == "a":
+            return GamutDiscriminatorA.from_json(data)
+        if tag == "b":
+            return GamutDiscriminatorB.from_json(data)
+        raise ValueError("unknown discriminator tag value: {!r}".format(tag))
+
+    def to_json(self) -> Dict[str, Any]:
+        raise NotImplementedError
+
+
+@dataclass
+class GamutDiscriminatorA(GamutDiscriminator):
+    a: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> GamutDiscriminatorA:
+        return cls(
+            a=data["a"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["tag"] = "a"
+        data["a"] = self.a
+        return data
+
+
+@dataclass
+class GamutDiscriminatorB(GamutDiscriminator):
+    b: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> GamutDiscriminatorB:
+        return cls(
+            b=data["b"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["tag"] = "b"
+        data["b"] = self.b
+        return data
+
+
+@dataclass
+class GamutElements:
+    a: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> GamutElements:
+        return cls(
+            a=data["a"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["a"] = self.a
+        return data
+
+
+class GamutEnum(Enum):
+    BAR = "BAR"
+    BAZ = "BAZ"
+    FOO = "FOO"
+
+    @classmethod
+    def from_json(cls, data: str) -> GamutEnum:
+        return cls(data)
+
+    def to_json(self) -> str:
+        return self.value
+
+
+@dataclass
+class GamutType:
+    a: bool
+    b: str
+    c: str
+    d: int
+    e: int
+    f: int
+    g: int
+    h: int
+    i: int
+    j: float
+    k: float
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> GamutType:
+        return cls(
+            a=data["a"],
+            b=data["b"],
+            c=data["c"],
+            d=data["d"],
+            e=data["e"],
+            f=data["f"],
+            g=data["g"],
+            h=data["h"],
+            i=data["i"],
+            j=data["j"],
+            k=data["k"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["a"] = self.a
+        data["b"] = self.b
+        data["c"] = self.c
+        data["d"] = self.d
+        data["e"] = self.e
+        data["f"] = self.f
+        data["g"] = self.g
+        data["h"] = self.h
+        data["i"] = self.i
+        data["j"] = self.j
+        data["k"] = self.k
+        return data
+
+
+@dataclass
+class GamutValues:
+    a: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> GamutValues:
+        return cls(
+            a=data["a"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["a"] = self.a
+        return data
+
+
+@dataclass
+class Gamut:
+    discriminator: GamutDiscriminator
+    elements: List[GamutElements]
+    empty: Any
+    enum: GamutEnum
+    ref: Ref
+    type: GamutType
+    values: Dict[str, GamutValues]
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Gamut:
+        return cls(
+            discriminator=GamutDiscriminator.from_json(data["discriminator"]),
+            elements=[GamutElements.from_json(v0) for v0 in data["elements"]],
+            empty=data["empty"],
+            enum=GamutEnum.from_json(data["enum"]),
+            ref=Ref.from_json(data["ref"]),
+            type=GamutType.from_json(data["type"]),
+            values={k0: GamutValues.from_json(v0) for k0, v0 in data["values"].items()},
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["discriminator"] = self.discriminator.to_json()
+        data["elements"] = [v0.to_json() for v0 in self.elements]
+        data["empty"] = self.empty
+        data["enum"] = self.enum.to_json()
+        data["ref"] = self.ref.to_json()
+        data["type"] = self.type.to_json()
+        data["values"] = {k0: v0.to_json() for k0, v0 in self.values.items()}
+        return data
+
+
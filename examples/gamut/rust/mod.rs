@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Ref {
+    #[serde(rename = "a")]
+    pub a: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GamutDiscriminatorA {
+    #[serde(rename = "a")]
+    pub a: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GamutDiscriminatorB {
+    #[serde(rename = "b")]
+    pub b: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "tag")]
+pub enum GamutDiscriminator {
+    #[serde(rename = "a")]
+    A(GamutDiscriminatorA),
+    #[serde(rename = "b")]
+    B(GamutDiscriminatorB),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GamutElements {
+    #[serde(rename = "a")]
+    pub a: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamutEnum {
+    #[serde(rename = "BAR")]
+    Bar,
+    #[serde(rename = "BAZ")]
+    Baz,
+    #[serde(rename = "FOO")]
+    Foo,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GamutType {
+    #[serde(rename = "a")]
+    pub a: bool,
+    #[serde(rename = "b")]
+    pub b: String,
+    #[serde(rename = "c")]
+    pub c: String,
+    #[serde(rename = "d")]
+    pub d: i8,
+    #[serde(rename = "e")]
+    pub e: u8,
+    #[serde(rename = "f")]
+    pub f: i16,
+    #[serde(rename = "g")]
+    pub g: u16,
+    #[serde(rename = "h")]
+    pub h: i32,
+    #[serde(rename = "i")]
+    pub i: u32,
+    #[serde(rename = "j")]
+    pub j: f32,
+    #[serde(rename = "k")]
+    pub k: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GamutValues {
+    #[serde(rename = "a")]
+    pub a: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Gamut {
+    #[serde(rename = "discriminator")]
+    pub discriminator: GamutDiscriminator,
+    #[serde(rename = "elements")]
+    pub elements: Vec<GamutElements>,
+    #[serde(rename = "empty")]
+    pub empty: serde_json::Value,
+    #[serde(rename = "enum")]
+    pub r#enum: GamutEnum,
+    #[serde(rename = "ref")]
+    pub r#ref: Ref,
+    #[serde(rename = "type")]
+    pub r#type: GamutType,
+    #[serde(rename = "values")]
+    pub values: std::collections::HashMap<String, GamutValues>,
+}
+
@@ -0,0 +1,178 @@
+import Foundation
+
+public enum JSONValue: Codable, Equatable {
+    case null
+    case bool(Bool)
+    case number(Double)
+    case string(String)
+    case array([JSONValue])
+    case object([String: JSONValue])
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if container.decodeNil() {
+            self = .null
+        } else if let value = try? container.decode(Bool.self) {
+            self = .bool(value)
+        } else if let value = try? container.decode(Double.self) {
+            self = .number(value)
+        } else if let value = try? container.decode(String.self) {
+            self = .string(value)
+        } else if let value = try? container.decode([JSONValue].self) {
+            self = .array(value)
+        } else {
+            self = .object(try container.decode([String: JSONValue].self))
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .null:
+            try container.encodeNil()
+        case .bool(let value):
+            try container.encode(value)
+        case .number(let value):
+            try container.encode(value)
+        case .string(let value):
+            try container.encode(value)
+        case .array(let value):
+            try container.encode(value)
+        case .object(let value):
+            try container.encode(value)
+        }
+    }
+}
+
+public struct Ref: Codable {
+    public var a: String
+
+    enum CodingKeys: String, CodingKey {
+        case a = "a"
+    }
+}
+
+public struct GamutDiscriminatorA: Codable {
+    public var a: String
+
+    enum CodingKeys: String, CodingKey {
+        case a = "a"
+    }
+}
+
+public struct GamutDiscriminatorB: Codable {
+    public var b: String
+
+    enum CodingKeys: String, CodingKey {
+        case b = "b"
+    }
+}
+
+public enum GamutDiscriminator: Codable {
+    case a(GamutDiscriminatorA)
+    case b(GamutDiscriminatorB)
+
+    enum CodingKeys: String, CodingKey {
+        case tag = "tag"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .tag)
+        switch tag {
+        case "a":
+            self = .a(try GamutDiscriminatorA(from: decoder))
+        case "b":
+            self = .b(try GamutDiscriminatorB(from: decoder))
+        default:
+            throw DecodingError.dataCorruptedError(
+                forKey: .tag,
+                in: container,
+                debugDescription: "unknown discriminator tag value: \(tag)"
+            )
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .a(let value):
+            try container.encode("a", forKey: .tag)
+            try value.encode(to: encoder)
+        case .b(let value):
+            try container.encode("b", forKey: .tag)
+            try value.encode(to: encoder)
+        }
+    }
+}
+
+public struct GamutElements: Codable {
+    public var a: String
+
+    enum CodingKeys: String, CodingKey {
+        case a = "a"
+    }
+}
+
+public enum GamutEnum: String, Codable {
+    case bar = "BAR"
+    case baz = "BAZ"
+    case foo = "FOO"
+}
+
+public struct GamutType: Codable {
+    public var a: Bool
+    public var b: String
+    public var c: String
+    public var d: Int8
+    public var e: UInt8
+    public var f: Int16
+    public var g: UInt16
+    public var h: Int32
+    public var i: UInt32
+    public var j: Float
+    public var k: Double
+
+    enum CodingKeys: String, CodingKey {
+        case a = "a"
+        case b = "b"
+        case c = "c"
+        case d = "d"
+        case e = "e"
+        case f = "f"
+        case g = "g"
+        case h = "h"
+        case i = "i"
+        case j = "j"
+        case k = "k"
+    }
+}
+
+public struct GamutValues: Codable {
+    public var a: String
+
+    enum CodingKeys: String, CodingKey {
+        case a = "a"
+    }
+}
+
+public struct Gamut: Codable {
+    public var discriminator: GamutDiscriminator
+    public var elements: [GamutElements]
+    public var empty: JSONValue
+    public var `enum`: GamutEnum
+    public var ref: Ref
+    public var type: GamutType
+    public var values: [String: GamutValues]
+
+    enum CodingKeys: String, CodingKey {
+        case discriminator = "discriminator"
+        case elements = "elements"
+        case empty = "empty"
+        case `enum` = "enum"
+        case ref = "ref"
+        case type = "type"
+        case values = "values"
+    }
+}
+
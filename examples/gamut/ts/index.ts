@@ -1,19 +1,15 @@
+// Code generated by jddf-codegen. DO NOT EDIT.
+//
+// Source: examples/gamut/gamut.jddf.json
+// Version: 0.1.0
+
 export interface Ref {
   a: string;
 }
 
-export interface GamutType {
-  a: boolean;
-  c: string;
-  j: number;
-  k: number;
-  b: string;
-  f: number;
-  d: number;
-  h: number;
-  e: number;
-  g: number;
-  i: number;
+export interface GamutDiscriminatorA {
+  tag: "a";
+  a: string;
 }
 
 export interface GamutDiscriminatorB {
@@ -21,26 +17,39 @@ export interface GamutDiscriminatorB {
   b: string;
 }
 
-export interface GamutDiscriminatorA {
-  tag: "a";
-  a: string;
-}
+export type GamutDiscriminator = GamutDiscriminatorA | GamutDiscriminatorB;
 
 export interface GamutElements {
   a: string;
 }
 
+export type GamutEnum = "BAR" | "BAZ" | "FOO";
+
+export interface GamutType {
+  a: boolean;
+  b: string;
+  c: string;
+  d: number;
+  e: number;
+  f: number;
+  g: number;
+  h: number;
+  i: number;
+  j: number;
+  k: number;
+}
+
 export interface GamutValues {
   a: string;
 }
 
 export interface Gamut {
-  type: GamutType;
-  ref: Ref;
-  enum: "FOO" | "BAR" | "BAZ";
-  discriminator: GamutDiscriminatorB | GamutDiscriminatorA;
+  discriminator: GamutDiscriminator;
   elements: GamutElements[];
   empty: any;
+  enum: GamutEnum;
+  ref: Ref;
+  type: GamutType;
   values: { [name: string]: GamutValues};
 }
 
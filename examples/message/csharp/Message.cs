@@ -0,0 +1,83 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example.Message;
+
+public record User
+{
+    [JsonPropertyName("id")]
+    public string Id { get; init; } = default!;
+
+    [JsonPropertyName("name")]
+    public string Name { get; init; } = default!;
+}
+
+public record MessageDetailsUserCreated : MessageDetails
+{
+    [JsonPropertyName("user")]
+    public User User { get; init; } = default!;
+}
+
+public record MessageDetailsUserDeleted : MessageDetails
+{
+    [JsonPropertyName("userId")]
+    public string UserId { get; init; } = default!;
+}
+
+[JsonConverter(typeof(MessageDetailsConverter))]
+public abstract record MessageDetails;
+
+public class MessageDetailsConverter : JsonConverter<MessageDetails>
+{
+    public override MessageDetails Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+    {
+        using var document = JsonDocument.ParseValue(ref reader);
+        var root = document.RootElement;
+        if (!root.TryGetProperty("type", out var tag) || tag.ValueKind != JsonValueKind.String)
+        {
+            throw new JsonException("missing discriminator tag");
+        }
+
+        return tag.GetString() switch
+        {
+            "user_created" => root.Deserialize<MessageDetailsUserCreated>(options)!,
+            "user_deleted" => root.Deserialize<MessageDetailsUserDeleted>(options)!,
+            var value => throw new JsonException($"unknown discriminator tag value: {value}"),
+        };
+    }
+
+    public override void Write(Utf8JsonWriter writer, MessageDetails value, JsonSerializerOptions options)
+    {
+        var (tag, element) = value switch
+        {
+            MessageDetailsUserCreated v => ("user_created", JsonSerializer.SerializeToElement(v, options)),
+            MessageDetailsUserDeleted v => ("user_deleted", JsonSerializer.SerializeToElement(v, options)),
+            _ => throw new JsonException("unknown discriminator variant"),
+        };
+
+        writer.WriteStartObject();
+        writer.WriteString("type", tag);
+        foreach (var property in element.EnumerateObject())
+        {
+            property.WriteTo(writer);
+        }
+        writer.WriteEndObject();
+    }
+}
+
+public record Message
+{
+    [JsonPropertyName("details")]
+    public MessageDetails Details { get; init; } = default!;
+
+    [JsonPropertyName("messageId")]
+    public string MessageId { get; init; } = default!;
+
+    [JsonPropertyName("timestamp")]
+    public DateTimeOffset Timestamp { get; init; } = default!;
+}
+
@@ -1,58 +1,327 @@
-package golang
-import "time"
-import "encoding/json"
-import "errors"
-var ErrUnknownVariant = errors.New("golang: unknown discriminator tag value")
+// Code generated by jddf-codegen. DO NOT EDIT.
+//
+// Source: examples/message/message.jddf.json
+// Version: 0.1.0
+
+package message
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strings"
+	"time"
+)
+
+var ErrUnknownVariant = errors.New("message: unknown discriminator tag value")
+
+// marshalVariant encodes a discriminator's variant, along with its tag.
+func marshalVariant(tag, value string, variant interface{}) ([]byte, error) {
+	b, err := json.Marshal(variant)
+	if err != nil {
+		return nil, err
+	}
+
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return nil, err
+	}
+	if obj[tag], err = json.Marshal(value); err != nil {
+		return nil, err
+	}
+	return json.Marshal(obj)
+}
+
+// ValidationError is a way in which a value does not satisfy the schema.
+// Both paths are JSON Pointers: InstancePath to the part of the value at
+// fault, and SchemaPath to the part of the schema it does not satisfy.
+type ValidationError struct {
+	InstancePath string
+	SchemaPath   string
+}
+
+func (e ValidationError) Error() string {
+	return fmt.Sprintf("message: value at %q does not satisfy schema at %q", e.InstancePath, e.SchemaPath)
+}
+
+// ValidationErrors is every way in which a value does not satisfy the schema.
+//
+// A struct checks the integers it holds as it is decoded, and reports any
+// which are out of range, or are not integers, among these. An integer
+// held by no struct is decoded by encoding/json alone, which fails to
+// decode it in those cases, and also where it is written as JDDF allows
+// but encoding/json does not, such as 1.0.
+type ValidationErrors []ValidationError
+
+func (e ValidationErrors) Error() string {
+	msgs := make([]string, len(e))
+	for i, err := range e {
+		msgs[i] = err.Error()
+	}
+	return strings.Join(msgs, "; ")
+}
+
 type User struct {
+	Id   string `json:"id"`
 	Name string `json:"name"`
-	Id string `json:"id"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
 }
 
-type MessageDetailsType = string
+func (v *User) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "id", "name":
+		default:
+			return fmt.Errorf("message: unknown property %q in User", key)
+		}
+	}
 
-const MessageDetailsTypeUserDeleted MessageDetailsType = "user_deleted"
+	v.missing = nil
+	if _, ok := obj["id"]; !ok {
+		v.missing = append(v.missing, "/properties/id")
+	}
+	if _, ok := obj["name"]; !ok {
+		v.missing = append(v.missing, "/properties/name")
+	}
+
+	type alias User
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v User) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v User) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/definitions/user" + missing})
+	}
+}
+
+type MessageDetailsType = string
 
 const MessageDetailsTypeUserCreated MessageDetailsType = "user_created"
 
+const MessageDetailsTypeUserDeleted MessageDetailsType = "user_deleted"
+
 type MessageDetails struct {
 	Type MessageDetailsType `json:"type"`
-	MessageDetailsUserDeleted
 	MessageDetailsUserCreated
+	MessageDetailsUserDeleted
 }
 
 func (v MessageDetails) MarshalJSON() ([]byte, error) {
 	switch v.Type {
-	case "user_deleted":
-		return json.Marshal(struct { Tag string `json:"type"`; MessageDetailsUserDeleted }{ Tag: "user_deleted", MessageDetailsUserDeleted: v.MessageDetailsUserDeleted });
 	case "user_created":
-		return json.Marshal(struct { Tag string `json:"type"`; MessageDetailsUserCreated }{ Tag: "user_created", MessageDetailsUserCreated: v.MessageDetailsUserCreated });
+		return marshalVariant("type", "user_created", &v.MessageDetailsUserCreated)
+	case "user_deleted":
+		return marshalVariant("type", "user_deleted", &v.MessageDetailsUserDeleted)
 	}
 	return nil, ErrUnknownVariant
 }
+
 func (v *MessageDetails) UnmarshalJSON(b []byte) error {
 	var obj map[string]interface{}
-	if err := json.Unmarshal(b, &obj); err != nil { return err }
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
 	tag, ok := obj["type"].(string)
-	if !ok { return ErrUnknownVariant }
+	if !ok {
+		return ErrUnknownVariant
+	}
 	v.Type = tag
 	switch tag {
-	case "user_deleted":
-		return json.Unmarshal(b, &v.MessageDetailsUserDeleted)
 	case "user_created":
 		return json.Unmarshal(b, &v.MessageDetailsUserCreated)
+	case "user_deleted":
+		return json.Unmarshal(b, &v.MessageDetailsUserDeleted)
 	}
 	return ErrUnknownVariant
 }
-type MessageDetailsUserDeleted struct {
-	UserId string `json:"userId"`
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v MessageDetails) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v MessageDetails) validate(path string, errs *ValidationErrors) {
+	switch v.Type {
+	case "user_created":
+		v.MessageDetailsUserCreated.validate(path, errs)
+	case "user_deleted":
+		v.MessageDetailsUserDeleted.validate(path, errs)
+	default:
+		*errs = append(*errs, ValidationError{InstancePath: path + "/type", SchemaPath: "/properties/details/discriminator/mapping"})
+	}
 }
+
 type MessageDetailsUserCreated struct {
 	User User `json:"user"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+}
+
+func (v *MessageDetailsUserCreated) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "type", "user":
+		default:
+			return fmt.Errorf("message: unknown property %q in MessageDetailsUserCreated", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["user"]; !ok {
+		v.missing = append(v.missing, "/properties/user")
+	}
+
+	type alias MessageDetailsUserCreated
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v MessageDetailsUserCreated) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v MessageDetailsUserCreated) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/properties/details/discriminator/mapping/user_created" + missing})
+	}
+	v.User.validate(path+"/user", errs)
+}
+
+type MessageDetailsUserDeleted struct {
+	UserId string `json:"userId"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+}
+
+func (v *MessageDetailsUserDeleted) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "type", "userId":
+		default:
+			return fmt.Errorf("message: unknown property %q in MessageDetailsUserDeleted", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["userId"]; !ok {
+		v.missing = append(v.missing, "/properties/userId")
+	}
+
+	type alias MessageDetailsUserDeleted
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v MessageDetailsUserDeleted) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v MessageDetailsUserDeleted) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/properties/details/discriminator/mapping/user_deleted" + missing})
+	}
 }
 
 type Message struct {
-	MessageId string `json:"messageId"`
-	Details MessageDetails `json:"details"`
-	Timestamp time.Time `json:"timestamp"`
+	Details   MessageDetails `json:"details"`
+	MessageId string         `json:"messageId"`
+	Timestamp time.Time      `json:"timestamp"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+}
+
+func (v *Message) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "details", "messageId", "timestamp":
+		default:
+			return fmt.Errorf("message: unknown property %q in Message", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["details"]; !ok {
+		v.missing = append(v.missing, "/properties/details")
+	}
+	if _, ok := obj["messageId"]; !ok {
+		v.missing = append(v.missing, "/properties/messageId")
+	}
+	if _, ok := obj["timestamp"]; !ok {
+		v.missing = append(v.missing, "/properties/timestamp")
+	}
+
+	type alias Message
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v Message) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
 }
 
+func (v Message) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: missing})
+	}
+	v.Details.validate(path+"/details", errs)
+}
@@ -0,0 +1,42 @@
+package com.example.message;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.time.OffsetDateTime;
+
+public class Message {
+    @JsonProperty("details")
+    private MessageDetails details;
+
+    @JsonProperty("messageId")
+    private String messageId;
+
+    @JsonProperty("timestamp")
+    private OffsetDateTime timestamp;
+
+    public Message() {
+    }
+
+    public MessageDetails getDetails() {
+        return details;
+    }
+
+    public void setDetails(MessageDetails details) {
+        this.details = details;
+    }
+
+    public String getMessageId() {
+        return messageId;
+    }
+
+    public void setMessageId(String messageId) {
+        this.messageId = messageId;
+    }
+
+    public OffsetDateTime getTimestamp() {
+        return timestamp;
+    }
+
+    public void setTimestamp(OffsetDateTime timestamp) {
+        this.timestamp = timestamp;
+    }
+}
@@ -0,0 +1,12 @@
+package com.example.message;
+
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+@JsonSubTypes({
+    @JsonSubTypes.Type(name = "user_created", value = MessageDetailsUserCreated.class),
+    @JsonSubTypes.Type(name = "user_deleted", value = MessageDetailsUserDeleted.class),
+})
+public abstract class MessageDetails {
+}
@@ -0,0 +1,19 @@
+package com.example.message;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public class MessageDetailsUserCreated extends MessageDetails {
+    @JsonProperty("user")
+    private User user;
+
+    public MessageDetailsUserCreated() {
+    }
+
+    public User getUser() {
+        return user;
+    }
+
+    public void setUser(User user) {
+        this.user = user;
+    }
+}
@@ -0,0 +1,19 @@
+package com.example.message;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public class MessageDetailsUserDeleted extends MessageDetails {
+    @JsonProperty("userId")
+    private String userId;
+
+    public MessageDetailsUserDeleted() {
+    }
+
+    public String getUserId() {
+        return userId;
+    }
+
+    public void setUserId(String userId) {
+        this.userId = userId;
+    }
+}
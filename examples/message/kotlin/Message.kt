@@ -0,0 +1,44 @@
+package com.example.message
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonClassDiscriminator
+
+@Serializable
+data class User(
+    @SerialName("id")
+    val id: String,
+    @SerialName("name")
+    val name: String,
+)
+
+@Serializable
+@SerialName("user_created")
+data class MessageDetailsUserCreated(
+    @SerialName("user")
+    val user: User,
+) : MessageDetails()
+
+@Serializable
+@SerialName("user_deleted")
+data class MessageDetailsUserDeleted(
+    @SerialName("userId")
+    val userId: String,
+) : MessageDetails()
+
+@OptIn(ExperimentalSerializationApi::class)
+@Serializable
+@JsonClassDiscriminator("type")
+sealed class MessageDetails
+
+@Serializable
+data class Message(
+    @SerialName("details")
+    val details: MessageDetails,
+    @SerialName("messageId")
+    val messageId: String,
+    @SerialName("timestamp")
+    val timestamp: String,
+)
+
@@ -0,0 +1,102 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from datetime import datetime
+from enum import Enum
+from typing import Any, Dict, List, Optional
+
+
+def _parse_timestamp(value: str) -> datetime:
+    # datetime.fromisoformat does not understand the "Z" suffix.
+    return datetime.fromisoformat(value.replace("Z", "+00:00"))
+
+
+@dataclass
+class User:
+    id: str
+    name: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> User:
+        return cls(
+            id=data["id"],
+            name=data["name"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["id"] = self.id
+        data["name"] = self.name
+        return data
+
+
+class MessageDetails:
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> MessageDetails:
+        tag = data["type"]
+        if tag == "user_created":
+            return MessageDetailsUserCreated.from_json(data)
+        if tag == "user_deleted":
+            return MessageDetailsUserDeleted.from_json(data)
+        raise ValueError("unknown discriminator tag value: {!r}".format(tag))
+
+    def to_json(self) -> Dict[str, Any]:
+        raise NotImplementedError
+
+
+@dataclass
+class MessageDetailsUserCreated(MessageDetails):
+    user: User
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> MessageDetailsUserCreated:
+        return cls(
+            user=User.from_json(data["user"]),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["type"] = "user_created"
+        data["user"] = self.user.to_json()
+        return data
+
+
+@dataclass
+class MessageDetailsUserDeleted(MessageDetails):
+    user_id: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> MessageDetailsUserDeleted:
+        return cls(
+            user_id=data["userId"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["type"] = "user_deleted"
+        data["userId"] = self.user_id
+        return data
+
+
+@dataclass
+class Message:
+    details: MessageDetails
+    message_id: str
+    timestamp: datetime
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Message:
+        return cls(
+            details=MessageDetails.from_json(data["details"]),
+            message_id=data["messageId"],
+            timestamp=_parse_timestamp(data["timestamp"]),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["details"] = self.details.to_json()
+        data["messageId"] = self.message_id
+        data["timestamp"] = self.timestamp.isoformat()
+        return data
+
+
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct User {
+    #[serde(rename = "id")]
+    pub id: String,
+    #[serde(rename = "name")]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MessageDetailsUserCreated {
+    #[serde(rename = "user")]
+    pub user: User,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MessageDetailsUserDeleted {
+    #[serde(rename = "userId")]
+    pub user_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum MessageDetails {
+    #[serde(rename = "user_created")]
+    UserCreated(MessageDetailsUserCreated),
+    #[serde(rename = "user_deleted")]
+    UserDeleted(MessageDetailsUserDeleted),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Message {
+    #[serde(rename = "details")]
+    pub details: MessageDetails,
+    #[serde(rename = "messageId")]
+    pub message_id: String,
+    #[serde(rename = "timestamp")]
+    pub timestamp: chrono::DateTime<chrono::FixedOffset>,
+}
+
@@ -0,0 +1,78 @@
+import Foundation
+
+public struct User: Codable {
+    public var id: String
+    public var name: String
+
+    enum CodingKeys: String, CodingKey {
+        case id = "id"
+        case name = "name"
+    }
+}
+
+public struct MessageDetailsUserCreated: Codable {
+    public var user: User
+
+    enum CodingKeys: String, CodingKey {
+        case user = "user"
+    }
+}
+
+public struct MessageDetailsUserDeleted: Codable {
+    public var userId: String
+
+    enum CodingKeys: String, CodingKey {
+        case userId = "userId"
+    }
+}
+
+public enum MessageDetails: Codable {
+    case userCreated(MessageDetailsUserCreated)
+    case userDeleted(MessageDetailsUserDeleted)
+
+    enum CodingKeys: String, CodingKey {
+        case tag = "type"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .tag)
+        switch tag {
+        case "user_created":
+            self = .userCreated(try MessageDetailsUserCreated(from: decoder))
+        case "user_deleted":
+            self = .userDeleted(try MessageDetailsUserDeleted(from: decoder))
+        default:
+            throw DecodingError.dataCorruptedError(
+                forKey: .tag,
+                in: container,
+                debugDescription: "unknown discriminator tag value: \(tag)"
+            )
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .userCreated(let value):
+            try container.encode("user_created", forKey: .tag)
+            try value.encode(to: encoder)
+        case .userDeleted(let value):
+            try container.encode("user_deleted", forKey: .tag)
+            try value.encode(to: encoder)
+        }
+    }
+}
+
+public struct Message: Codable {
+    public var details: MessageDetails
+    public var messageId: String
+    public var timestamp: Date
+
+    enum CodingKeys: String, CodingKey {
+        case details = "details"
+        case messageId = "messageId"
+        case timestamp = "timestamp"
+    }
+}
+
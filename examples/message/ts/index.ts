@@ -1,21 +1,28 @@
+// Code generated by jddf-codegen. DO NOT EDIT.
+//
+// Source: examples/message/message.jddf.json
+// Version: 0.1.0
+
 export interface User {
   id: string;
   name: string;
 }
 
+export interface MessageDetailsUserCreated {
+  type: "user_created";
+  user: User;
+}
+
 export interface MessageDetailsUserDeleted {
   type: "user_deleted";
   userId: string;
 }
 
-export interface MessageDetailsUserCreated {
-  type: "user_created";
-  user: User;
-}
+export type MessageDetails = MessageDetailsUserCreated | MessageDetailsUserDeleted;
 
 export interface Message {
-  details: MessageDetailsUserDeleted | MessageDetailsUserCreated;
-  timestamp: string;
+  details: MessageDetails;
   messageId: string;
+  timestamp: string;
 }
 
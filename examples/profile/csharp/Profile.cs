@@ -0,0 +1,153 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example.Profile;
+
+[JsonConverter(typeof(AccountStatusConverter))]
+public enum AccountStatus
+{
+    Active,
+    Inactive,
+}
+
+public class AccountStatusConverter : JsonConverter<AccountStatus>
+{
+    public override AccountStatus Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+    {
+        return reader.GetString() switch
+        {
+            "ACTIVE" => AccountStatus.Active,
+            "INACTIVE" => AccountStatus.Inactive,
+            var value => throw new JsonException($"unknown AccountStatus value: {value}"),
+        };
+    }
+
+    public override void Write(Utf8JsonWriter writer, AccountStatus value, JsonSerializerOptions options)
+    {
+        writer.WriteStringValue(value switch
+        {
+            AccountStatus.Active => "ACTIVE",
+            AccountStatus.Inactive => "INACTIVE",
+            _ => throw new JsonException($"unknown AccountStatus value: {value}"),
+        });
+    }
+}
+
+public record ProfileAddress
+{
+    [JsonPropertyName("street")]
+    public string Street { get; init; } = default!;
+}
+
+public record ProfileLastLoginPassword : ProfileLastLogin
+{
+}
+
+public record SingleSignOn : ProfileLastLogin
+{
+    [JsonPropertyName("provider")]
+    public string? Provider { get; init; } = default!;
+}
+
+[JsonConverter(typeof(ProfileLastLoginConverter))]
+public abstract record ProfileLastLogin;
+
+public class ProfileLastLoginConverter : JsonConverter<ProfileLastLogin>
+{
+    public override ProfileLastLogin Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+    {
+        using var document = JsonDocument.ParseValue(ref reader);
+        var root = document.RootElement;
+        if (!root.TryGetProperty("method", out var tag) || tag.ValueKind != JsonValueKind.String)
+        {
+            throw new JsonException("missing discriminator tag");
+        }
+
+        return tag.GetString() switch
+        {
+            "password" => root.Deserialize<ProfileLastLoginPassword>(options)!,
+            "sso" => root.Deserialize<SingleSignOn>(options)!,
+            var value => throw new JsonException($"unknown discriminator tag value: {value}"),
+        };
+    }
+
+    public override void Write(Utf8JsonWriter writer, ProfileLastLogin value, JsonSerializerOptions options)
+    {
+        var (tag, element) = value switch
+        {
+            ProfileLastLoginPassword v => ("password", JsonSerializer.SerializeToElement(v, options)),
+            SingleSignOn v => ("sso", JsonSerializer.SerializeToElement(v, options)),
+            _ => throw new JsonException("unknown discriminator variant"),
+        };
+
+        writer.WriteStartObject();
+        writer.WriteString("method", tag);
+        foreach (var property in element.EnumerateObject())
+        {
+            property.WriteTo(writer);
+        }
+        writer.WriteEndObject();
+    }
+}
+
+public record ProfilePreferences
+{
+    [JsonPropertyName("theme")]
+    public string Theme { get; init; } = default!;
+}
+
+public record Profile
+{
+    [JsonPropertyName("address")]
+    public ProfileAddress? Address { get; init; } = default!;
+
+    [JsonPropertyName("id")]
+    public string Id { get; init; } = default!;
+
+    [JsonPropertyName("lastLogin")]
+    public ProfileLastLogin? LastLogin { get; init; } = default!;
+
+    [JsonPropertyName("name")]
+    public string? Name { get; init; } = default!;
+
+    [JsonPropertyName("nickname")]
+    public string? Nickname { get; init; } = default!;
+
+    [JsonPropertyName("scores")]
+    public Dictionary<string, double?> Scores { get; init; } = default!;
+
+    [JsonPropertyName("status")]
+    public AccountStatus? Status { get; init; } = default!;
+
+    [JsonPropertyName("tags")]
+    public List<string?>? Tags { get; init; } = default!;
+
+    [JsonPropertyName("age")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public uint? Age { get; init; }
+
+    [JsonPropertyName("anything")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonElement? Anything { get; init; }
+
+    [JsonPropertyName("friends")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public List<string?>? Friends { get; init; }
+
+    [JsonPropertyName("middleName")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? MiddleName { get; init; }
+
+    [JsonPropertyName("preferences")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public ProfilePreferences? Preferences { get; init; }
+
+    [JsonPropertyName("previousStatus")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public AccountStatus? PreviousStatus { get; init; }
+}
+
@@ -0,0 +1,523 @@
+// Code generated by jddf-codegen. DO NOT EDIT.
+//
+// Source: examples/profile/profile.jddf.json
+// Version: 0.1.0
+// Requires Go 1.24 or later.
+
+package profile
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/google/uuid"
+)
+
+var ErrUnknownVariant = errors.New("profile: unknown discriminator tag value")
+
+// marshalVariant encodes a discriminator's variant, along with its tag.
+func marshalVariant(tag, value string, variant interface{}) ([]byte, error) {
+	b, err := json.Marshal(variant)
+	if err != nil {
+		return nil, err
+	}
+
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return nil, err
+	}
+	if obj[tag], err = json.Marshal(value); err != nil {
+		return nil, err
+	}
+	return json.Marshal(obj)
+}
+
+// InvalidEnumValueError is returned when encoding or decoding a value
+// which an enum does not allow.
+type InvalidEnumValueError struct {
+	Type  string
+	Value string
+}
+
+func (e *InvalidEnumValueError) Error() string {
+	return fmt.Sprintf("profile: invalid %s value: %q", e.Type, e.Value)
+}
+
+// ValidationError is a way in which a value does not satisfy the schema.
+// Both paths are JSON Pointers: InstancePath to the part of the value at
+// fault, and SchemaPath to the part of the schema it does not satisfy.
+type ValidationError struct {
+	InstancePath string
+	SchemaPath   string
+}
+
+func (e ValidationError) Error() string {
+	return fmt.Sprintf("profile: value at %q does not satisfy schema at %q", e.InstancePath, e.SchemaPath)
+}
+
+// ValidationErrors is every way in which a value does not satisfy the schema.
+//
+// A struct checks the integers it holds as it is decoded, and reports any
+// which are out of range, or are not integers, among these. An integer
+// held by no struct is decoded by encoding/json alone, which fails to
+// decode it in those cases, and also where it is written as JDDF allows
+// but encoding/json does not, such as 1.0.
+type ValidationErrors []ValidationError
+
+func (e ValidationErrors) Error() string {
+	msgs := make([]string, len(e))
+	for i, err := range e {
+		msgs[i] = err.Error()
+	}
+	return strings.Join(msgs, "; ")
+}
+
+// decodeInteger returns the integer a JSON value is, if it is one within
+// [min, max]. Unlike encoding/json, JDDF allows an integer to be written
+// with a fractional part or exponent, such as 1.0 or 1e2.
+func decodeInteger(b json.RawMessage, min, max float64) (int64, bool) {
+	f, err := strconv.ParseFloat(string(b), 64)
+	if err != nil || f < min || f > max || f != float64(int64(f)) {
+		return 0, false
+	}
+	return int64(f), true
+}
+
+// Nullable is an optional property which may also be null. Present is
+// false if the property is absent, and Value is nil if it is null.
+type Nullable[T any] struct {
+	Value   *T
+	Present bool
+}
+
+func (n Nullable[T]) IsZero() bool {
+	return !n.Present
+}
+
+func (n Nullable[T]) MarshalJSON() ([]byte, error) {
+	return json.Marshal(n.Value)
+}
+
+func (n *Nullable[T]) UnmarshalJSON(b []byte) error {
+	n.Present = true
+	return json.Unmarshal(b, &n.Value)
+}
+
+type Name = string
+
+// Whether the account is in use.
+type AccountStatus string
+
+// The user has signed in recently.
+const AccountStatusACTIVE AccountStatus = "ACTIVE"
+
+// The user has not signed in for a year or more.
+const AccountStatusINACTIVE AccountStatus = "INACTIVE"
+
+// AllAccountStatusValues is every value AccountStatus can take on.
+var AllAccountStatusValues = []AccountStatus{
+	AccountStatusACTIVE,
+	AccountStatusINACTIVE,
+}
+
+func (v AccountStatus) String() string {
+	return string(v)
+}
+
+// IsValid reports whether v is one of AllAccountStatusValues.
+func (v AccountStatus) IsValid() bool {
+	switch v {
+	case AccountStatusACTIVE, AccountStatusINACTIVE:
+		return true
+	}
+	return false
+}
+
+func (v AccountStatus) MarshalJSON() ([]byte, error) {
+	if !v.IsValid() {
+		return nil, &InvalidEnumValueError{Type: "AccountStatus", Value: string(v)}
+	}
+	return json.Marshal(string(v))
+}
+
+func (v *AccountStatus) UnmarshalJSON(b []byte) error {
+	var s string
+	if err := json.Unmarshal(b, &s); err != nil {
+		return err
+	}
+	if !AccountStatus(s).IsValid() {
+		return &InvalidEnumValueError{Type: "AccountStatus", Value: s}
+	}
+	*v = AccountStatus(s)
+	return nil
+}
+
+func (v AccountStatus) validate(path string, errs *ValidationErrors) {
+	if !v.IsValid() {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/definitions/status/enum"})
+	}
+}
+
+type ProfileAddress struct {
+	Street string `json:"street"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+}
+
+func (v *ProfileAddress) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "street":
+		default:
+			return fmt.Errorf("profile: unknown property %q in ProfileAddress", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["street"]; !ok {
+		v.missing = append(v.missing, "/properties/street")
+	}
+
+	type alias ProfileAddress
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v ProfileAddress) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v ProfileAddress) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/properties/address" + missing})
+	}
+}
+
+type ProfileLastLoginMethod = string
+
+const ProfileLastLoginMethodPassword ProfileLastLoginMethod = "password"
+
+const ProfileLastLoginMethodSso ProfileLastLoginMethod = "sso"
+
+// How the user last signed in, if they ever have.
+type ProfileLastLogin struct {
+	Method ProfileLastLoginMethod `json:"method"`
+	ProfileLastLoginPassword
+	SingleSignOn
+}
+
+func (v ProfileLastLogin) MarshalJSON() ([]byte, error) {
+	switch v.Method {
+	case "password":
+		return marshalVariant("method", "password", &v.ProfileLastLoginPassword)
+	case "sso":
+		return marshalVariant("method", "sso", &v.SingleSignOn)
+	}
+	return nil, ErrUnknownVariant
+}
+
+func (v *ProfileLastLogin) UnmarshalJSON(b []byte) error {
+	var obj map[string]interface{}
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	tag, ok := obj["method"].(string)
+	if !ok {
+		return ErrUnknownVariant
+	}
+	v.Method = tag
+	switch tag {
+	case "password":
+		return json.Unmarshal(b, &v.ProfileLastLoginPassword)
+	case "sso":
+		return json.Unmarshal(b, &v.SingleSignOn)
+	}
+	return ErrUnknownVariant
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v ProfileLastLogin) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v ProfileLastLogin) validate(path string, errs *ValidationErrors) {
+	switch v.Method {
+	case "password":
+		v.ProfileLastLoginPassword.validate(path, errs)
+	case "sso":
+		v.SingleSignOn.validate(path, errs)
+	default:
+		*errs = append(*errs, ValidationError{InstancePath: path + "/method", SchemaPath: "/properties/lastLogin/discriminator/mapping"})
+	}
+}
+
+type ProfileLastLoginPassword struct{}
+
+func (v *ProfileLastLoginPassword) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "method":
+		default:
+			return fmt.Errorf("profile: unknown property %q in ProfileLastLoginPassword", key)
+		}
+	}
+
+	type alias ProfileLastLoginPassword
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v ProfileLastLoginPassword) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v ProfileLastLoginPassword) validate(path string, errs *ValidationErrors) {}
+
+// Signed in through an identity provider.
+type SingleSignOn struct {
+	Provider *string `json:"provider"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+}
+
+func (v *SingleSignOn) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "method", "provider":
+		default:
+			return fmt.Errorf("profile: unknown property %q in SingleSignOn", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["provider"]; !ok {
+		v.missing = append(v.missing, "/properties/provider")
+	}
+
+	type alias SingleSignOn
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v SingleSignOn) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v SingleSignOn) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/properties/lastLogin/discriminator/mapping/sso" + missing})
+	}
+}
+
+type ProfilePreferences struct {
+	Theme string `json:"theme"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+}
+
+func (v *ProfilePreferences) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+
+	v.missing = nil
+	if _, ok := obj["theme"]; !ok {
+		v.missing = append(v.missing, "/properties/theme")
+	}
+
+	type alias ProfilePreferences
+	return json.Unmarshal(b, (*alias)(v))
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v ProfilePreferences) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v ProfilePreferences) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: "/optionalProperties/preferences" + missing})
+	}
+}
+
+// A user's public profile.
+type Profile struct {
+	Address *ProfileAddress `json:"address"`
+	// A unique, opaque identifier for the user.
+	Id uuid.UUID `json:"id"`
+	// How the user last signed in, if they ever have.
+	LastLogin *ProfileLastLogin `json:"lastLogin"`
+	Name      *Name             `json:"name"`
+	// What the user would like to be called.
+	//
+	// Null if they have chosen not to say.
+	Nickname       *string                 `json:"nickname"`
+	Scores         map[string]*float64     `json:"scores"`
+	Status         *AccountStatus          `json:"status"`
+	Tags           *[]*string              `json:"tags"`
+	Age            *uint32                 `json:"age,omitempty"`
+	Anything       *interface{}            `json:"anything,omitempty"`
+	Friends        Nullable[[]*Name]       `json:"friends,omitzero"`
+	MiddleName     Nullable[string]        `json:"middleName,omitzero"`
+	Preferences    *ProfilePreferences     `json:"preferences,omitempty"`
+	PreviousStatus Nullable[AccountStatus] `json:"previousStatus,omitzero"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+
+	// the ways in which the integers in the JSON it was decoded from did not
+	// satisfy the schema, with instance paths relative to the struct's own
+	invalid []ValidationError
+}
+
+func (v *Profile) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "address", "id", "lastLogin", "name", "nickname", "scores", "status", "tags", "age", "anything", "friends", "middleName", "preferences", "previousStatus":
+		default:
+			return fmt.Errorf("profile: unknown property %q in Profile", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["address"]; !ok {
+		v.missing = append(v.missing, "/properties/address")
+	}
+	if _, ok := obj["id"]; !ok {
+		v.missing = append(v.missing, "/properties/id")
+	}
+	if _, ok := obj["lastLogin"]; !ok {
+		v.missing = append(v.missing, "/properties/lastLogin")
+	}
+	if _, ok := obj["name"]; !ok {
+		v.missing = append(v.missing, "/properties/name")
+	}
+	if _, ok := obj["nickname"]; !ok {
+		v.missing = append(v.missing, "/properties/nickname")
+	}
+	if _, ok := obj["scores"]; !ok {
+		v.missing = append(v.missing, "/properties/scores")
+	}
+	if _, ok := obj["status"]; !ok {
+		v.missing = append(v.missing, "/properties/status")
+	}
+	if _, ok := obj["tags"]; !ok {
+		v.missing = append(v.missing, "/properties/tags")
+	}
+
+	type alias Profile
+	var numbers struct {
+		alias
+		Age *json.RawMessage `json:"age"`
+	}
+	numbers.alias = alias(*v)
+	if err := json.Unmarshal(b, &numbers); err != nil {
+		return err
+	}
+	*v = Profile(numbers.alias)
+
+	v.invalid = nil
+	if numbers.Age == nil {
+		v.Age = nil
+	} else {
+		v.Age = new(uint32)
+		if n, ok := decodeInteger(*numbers.Age, 0, 4294967295); ok {
+			*v.Age = uint32(n)
+		} else {
+			v.invalid = append(v.invalid, ValidationError{InstancePath: "/age", SchemaPath: "/optionalProperties/age/type"})
+		}
+	}
+	return nil
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v Profile) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v Profile) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: missing})
+	}
+	for _, invalid := range v.invalid {
+		*errs = append(*errs, ValidationError{InstancePath: path + invalid.InstancePath, SchemaPath: invalid.SchemaPath})
+	}
+	if v.Address != nil {
+		v.Address.validate(path+"/address", errs)
+	}
+	if v.LastLogin != nil {
+		v.LastLogin.validate(path+"/lastLogin", errs)
+	}
+	if v.Status != nil {
+		v.Status.validate(path+"/status", errs)
+	}
+	if v.Preferences != nil {
+		v.Preferences.validate(path+"/preferences", errs)
+	}
+	if v.PreviousStatus.Value != nil {
+		v.PreviousStatus.Value.validate(path+"/previousStatus", errs)
+	}
+}
@@ -0,0 +1,11 @@
+package com.example.profile;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public enum AccountStatus {
+    @JsonProperty("ACTIVE")
+    ACTIVE,
+
+    @JsonProperty("INACTIVE")
+    INACTIVE,
+}
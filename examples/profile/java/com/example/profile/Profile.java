@@ -0,0 +1,171 @@
+package com.example.profile;
+
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.List;
+import java.util.Map;
+
+public class Profile {
+    @JsonProperty("address")
+    private ProfileAddress address;
+
+    @JsonProperty("id")
+    private String id;
+
+    @JsonProperty("lastLogin")
+    private ProfileLastLogin lastLogin;
+
+    @JsonProperty("name")
+    private String name;
+
+    @JsonProperty("nickname")
+    private String nickname;
+
+    @JsonProperty("scores")
+    private Map<String, Double> scores;
+
+    @JsonProperty("status")
+    private AccountStatus status;
+
+    @JsonProperty("tags")
+    private List<String> tags;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("age")
+    private Long age;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("anything")
+    private Object anything;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("friends")
+    private List<String> friends;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("middleName")
+    private String middleName;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("preferences")
+    private ProfilePreferences preferences;
+
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    @JsonProperty("previousStatus")
+    private AccountStatus previousStatus;
+
+    public Profile() {
+    }
+
+    public ProfileAddress getAddress() {
+        return address;
+    }
+
+    public void setAddress(ProfileAddress address) {
+        this.address = address;
+    }
+
+    public String getId() {
+        return id;
+    }
+
+    public void setId(String id) {
+        this.id = id;
+    }
+
+    public ProfileLastLogin getLastLogin() {
+        return lastLogin;
+    }
+
+    public void setLastLogin(ProfileLastLogin lastLogin) {
+        this.lastLogin = lastLogin;
+    }
+
+    public String getName() {
+        return name;
+    }
+
+    public void setName(String name) {
+        this.name = name;
+    }
+
+    public String getNickname() {
+        return nickname;
+    }
+
+    public void setNickname(String nickname) {
+        this.nickname = nickname;
+    }
+
+    public Map<String, Double> getScores() {
+        return scores;
+    }
+
+    public void setScores(Map<String, Double> scores) {
+        this.scores = scores;
+    }
+
+    public AccountStatus getStatus() {
+        return status;
+    }
+
+    public void setStatus(AccountStatus status) {
+        this.status = status;
+    }
+
+    public List<String> getTags() {
+        return tags;
+    }
+
+    public void setTags(List<String> tags) {
+        this.tags = tags;
+    }
+
+    public Long getAge() {
+        return age;
+    }
+
+    public void setAge(Long age) {
+        this.age = age;
+    }
+
+    public Object getAnything() {
+        return anything;
+    }
+
+    public void setAnything(Object anything) {
+        this.anything = anything;
+    }
+
+    public List<String> getFriends() {
+        return friends;
+    }
+
+    public void setFriends(List<String> friends) {
+        this.friends = friends;
+    }
+
+    public String getMiddleName() {
+        return middleName;
+    }
+
+    public void setMiddleName(String middleName) {
+        this.middleName = middleName;
+    }
+
+    public ProfilePreferences getPreferences() {
+        return preferences;
+    }
+
+    public void setPreferences(ProfilePreferences preferences) {
+        this.preferences = preferences;
+    }
+
+    public AccountStatus getPreviousStatus() {
+        return previousStatus;
+    }
+
+    public void setPreviousStatus(AccountStatus previousStatus) {
+        this.previousStatus = previousStatus;
+    }
+}
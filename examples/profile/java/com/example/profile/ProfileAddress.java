@@ -0,0 +1,19 @@
+package com.example.profile;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public class ProfileAddress {
+    @JsonProperty("street")
+    private String street;
+
+    public ProfileAddress() {
+    }
+
+    public String getStreet() {
+        return street;
+    }
+
+    public void setStreet(String street) {
+        this.street = street;
+    }
+}
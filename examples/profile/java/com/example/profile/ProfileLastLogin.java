@@ -0,0 +1,12 @@
+package com.example.profile;
+
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "method")
+@JsonSubTypes({
+    @JsonSubTypes.Type(name = "password", value = ProfileLastLoginPassword.class),
+    @JsonSubTypes.Type(name = "sso", value = SingleSignOn.class),
+})
+public abstract class ProfileLastLogin {
+}
@@ -0,0 +1,6 @@
+package com.example.profile;
+
+public class ProfileLastLoginPassword extends ProfileLastLogin {
+    public ProfileLastLoginPassword() {
+    }
+}
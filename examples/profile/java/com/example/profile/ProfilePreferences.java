@@ -0,0 +1,19 @@
+package com.example.profile;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public class ProfilePreferences {
+    @JsonProperty("theme")
+    private String theme;
+
+    public ProfilePreferences() {
+    }
+
+    public String getTheme() {
+        return theme;
+    }
+
+    public void setTheme(String theme) {
+        this.theme = theme;
+    }
+}
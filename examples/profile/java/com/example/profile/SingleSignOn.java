@@ -0,0 +1,19 @@
+package com.example.profile;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+public class SingleSignOn extends ProfileLastLogin {
+    @JsonProperty("provider")
+    private String provider;
+
+    public SingleSignOn() {
+    }
+
+    public String getProvider() {
+        return provider;
+    }
+
+    public void setProvider(String provider) {
+        this.provider = provider;
+    }
+}
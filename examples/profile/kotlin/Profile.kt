@@ -0,0 +1,78 @@
+package com.example.profile
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonClassDiscriminator
+import kotlinx.serialization.json.JsonElement
+
+typealias Name = String
+
+@Serializable
+enum class AccountStatus {
+    @SerialName("ACTIVE")
+    ACTIVE,
+    @SerialName("INACTIVE")
+    INACTIVE,
+}
+
+@Serializable
+data class ProfileAddress(
+    @SerialName("street")
+    val street: String,
+)
+
+@Serializable
+@SerialName("password")
+class ProfileLastLoginPassword : ProfileLastLogin()
+
+@Serializable
+@SerialName("sso")
+data class SingleSignOn(
+    @SerialName("provider")
+    val provider: String?,
+) : ProfileLastLogin()
+
+@OptIn(ExperimentalSerializationApi::class)
+@Serializable
+@JsonClassDiscriminator("method")
+sealed class ProfileLastLogin
+
+@Serializable
+data class ProfilePreferences(
+    @SerialName("theme")
+    val theme: String,
+)
+
+@Serializable
+data class Profile(
+    @SerialName("address")
+    val address: ProfileAddress?,
+    @SerialName("id")
+    val id: String,
+    @SerialName("lastLogin")
+    val lastLogin: ProfileLastLogin?,
+    @SerialName("name")
+    val name: Name?,
+    @SerialName("nickname")
+    val nickname: String?,
+    @SerialName("scores")
+    val scores: Map<String, Double?>,
+    @SerialName("status")
+    val status: AccountStatus?,
+    @SerialName("tags")
+    val tags: List<String?>?,
+    @SerialName("age")
+    val age: Long? = null,
+    @SerialName("anything")
+    val anything: JsonElement? = null,
+    @SerialName("friends")
+    val friends: List<Name?>? = null,
+    @SerialName("middleName")
+    val middleName: String? = null,
+    @SerialName("preferences")
+    val preferences: ProfilePreferences? = null,
+    @SerialName("previousStatus")
+    val previousStatus: AccountStatus? = null,
+)
+
@@ -0,0 +1,164 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from datetime import datetime
+from enum import Enum
+from typing import Any, Dict, List, Optional
+
+
+def _parse_timestamp(value: str) -> datetime:
+    # datetime.fromisoformat does not understand the "Z" suffix.
+    return datetime.fromisoformat(value.replace("Z", "+00:00"))
+
+
+class AccountStatus(Enum):
+    ACTIVE = "ACTIVE"
+    INACTIVE = "INACTIVE"
+
+    @classmethod
+    def from_json(cls, data: str) -> AccountStatus:
+        return cls(data)
+
+    def to_json(self) -> str:
+        return self.value
+
+
+@dataclass
+class ProfileAddress:
+    street: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ProfileAddress:
+        return cls(
+            street=data["street"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["street"] = self.street
+        return data
+
+
+class ProfileLastLogin:
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ProfileLastLogin:
+        tag = data["method"]
+        if tag == "password":
+            return ProfileLastLoginPassword.from_json(data)
+        if tag == "sso":
+            return SingleSignOn.from_json(data)
+        raise ValueError("unknown discriminator tag value: {!r}".format(tag))
+
+    def to_json(self) -> Dict[str, Any]:
+        raise NotImplementedError
+
+
+@dataclass
+class ProfileLastLoginPassword(ProfileLastLogin):
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ProfileLastLoginPassword:
+        return cls(
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["method"] = "password"
+        return data
+
+
+@dataclass
+class SingleSignOn(ProfileLastLogin):
+    provider: Optional[str]
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> SingleSignOn:
+        return cls(
+            provider=data["provider"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["method"] = "sso"
+        data["provider"] = self.provider
+        return data
+
+
+@dataclass
+class ProfilePreferences:
+    theme: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ProfilePreferences:
+        return cls(
+            theme=data["theme"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["theme"] = self.theme
+        return data
+
+
+@dataclass
+class Profile:
+    address: Optional[ProfileAddress]
+    id: str
+    last_login: Optional[ProfileLastLogin]
+    name: Optional[Name]
+    nickname: Optional[str]
+    scores: Dict[str, Optional[float]]
+    status: Optional[AccountStatus]
+    tags: Optional[List[Optional[str]]]
+    age: Optional[int] = None
+    anything: Optional[Any] = None
+    friends: Optional[List[Optional[Name]]] = None
+    middle_name: Optional[str] = None
+    preferences: Optional[ProfilePreferences] = None
+    previous_status: Optional[AccountStatus] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Profile:
+        return cls(
+            address=None if data["address"] is None else ProfileAddress.from_json(data["address"]),
+            id=data["id"],
+            last_login=None if data["lastLogin"] is None else ProfileLastLogin.from_json(data["lastLogin"]),
+            name=data["name"],
+            nickname=data["nickname"],
+            scores={k0: v0 for k0, v0 in data["scores"].items()},
+            status=None if data["status"] is None else AccountStatus.from_json(data["status"]),
+            tags=None if data["tags"] is None else [v0 for v0 in data["tags"]],
+            age=None if data.get("age") is None else data["age"],
+            anything=None if data.get("anything") is None else data["anything"],
+            friends=None if data.get("friends") is None else [v0 for v0 in data["friends"]],
+            middle_name=None if data.get("middleName") is None else data["middleName"],
+            preferences=None if data.get("preferences") is None else ProfilePreferences.from_json(data["preferences"]),
+            previous_status=None if data.get("previousStatus") is None else AccountStatus.from_json(data["previousStatus"]),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["address"] = None if self.address is None else self.address.to_json()
+        data["id"] = self.id
+        data["lastLogin"] = None if self.last_login is None else self.last_login.to_json()
+        data["name"] = self.name
+        data["nickname"] = self.nickname
+        data["scores"] = {k0: v0 for k0, v0 in self.scores.items()}
+        data["status"] = None if self.status is None else self.status.to_json()
+        data["tags"] = None if self.tags is None else [v0 for v0 in self.tags]
+        if self.age is not None:
+            data["age"] = self.age
+        if self.anything is not None:
+            data["anything"] = self.anything
+        if self.friends is not None:
+            data["friends"] = [v0 for v0 in self.friends]
+        if self.middle_name is not None:
+            data["middleName"] = self.middle_name
+        if self.preferences is not None:
+            data["preferences"] = self.preferences.to_json()
+        if self.previous_status is not None:
+            data["previousStatus"] = self.previous_status.to_json()
+        return data
+
+
+Name = str
+
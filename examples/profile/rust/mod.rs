@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    Deserialize::deserialize(deserializer).map(Some)
+}
+
+pub type Name = String;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccountStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ProfileAddress {
+    #[serde(rename = "street")]
+    pub street: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ProfileLastLoginPassword {
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SingleSignOn {
+    #[serde(rename = "provider")]
+    pub provider: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "method")]
+pub enum ProfileLastLogin {
+    #[serde(rename = "password")]
+    Password(ProfileLastLoginPassword),
+    #[serde(rename = "sso")]
+    Sso(SingleSignOn),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ProfilePreferences {
+    #[serde(rename = "theme")]
+    pub theme: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Profile {
+    #[serde(rename = "address")]
+    pub address: Option<ProfileAddress>,
+    #[serde(rename = "id")]
+    pub id: String,
+    #[serde(rename = "lastLogin")]
+    pub last_login: Option<ProfileLastLogin>,
+    #[serde(rename = "name")]
+    pub name: Option<Name>,
+    #[serde(rename = "nickname")]
+    pub nickname: Option<String>,
+    #[serde(rename = "scores")]
+    pub scores: std::collections::HashMap<String, Option<f64>>,
+    #[serde(rename = "status")]
+    pub status: Option<AccountStatus>,
+    #[serde(rename = "tags")]
+    pub tags: Option<Vec<Option<String>>>,
+    #[serde(rename = "age", skip_serializing_if = "Option::is_none")]
+    pub age: Option<u32>,
+    #[serde(rename = "anything", default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_some")]
+    pub anything: Option<serde_json::Value>,
+    #[serde(rename = "friends", default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_some")]
+    pub friends: Option<Option<Vec<Option<Name>>>>,
+    #[serde(rename = "middleName", default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_some")]
+    pub middle_name: Option<Option<String>>,
+    #[serde(rename = "preferences", skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<ProfilePreferences>,
+    #[serde(rename = "previousStatus", default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_some")]
+    pub previous_status: Option<Option<AccountStatus>>,
+}
+
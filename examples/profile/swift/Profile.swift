@@ -0,0 +1,152 @@
+import Foundation
+
+public enum JSONValue: Codable, Equatable {
+    case null
+    case bool(Bool)
+    case number(Double)
+    case string(String)
+    case array([JSONValue])
+    case object([String: JSONValue])
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if container.decodeNil() {
+            self = .null
+        } else if let value = try? container.decode(Bool.self) {
+            self = .bool(value)
+        } else if let value = try? container.decode(Double.self) {
+            self = .number(value)
+        } else if let value = try? container.decode(String.self) {
+            self = .string(value)
+        } else if let value = try? container.decode([JSONValue].self) {
+            self = .array(value)
+        } else {
+            self = .object(try container.decode([String: JSONValue].self))
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .null:
+            try container.encodeNil()
+        case .bool(let value):
+            try container.encode(value)
+        case .number(let value):
+            try container.encode(value)
+        case .string(let value):
+            try container.encode(value)
+        case .array(let value):
+            try container.encode(value)
+        case .object(let value):
+            try container.encode(value)
+        }
+    }
+}
+
+public typealias Name = String
+
+public enum AccountStatus: String, Codable {
+    case active = "ACTIVE"
+    case inactive = "INACTIVE"
+}
+
+public struct ProfileAddress: Codable {
+    public var street: String
+
+    enum CodingKeys: String, CodingKey {
+        case street = "street"
+    }
+}
+
+public struct ProfileLastLoginPassword: Codable {
+}
+
+public struct SingleSignOn: Codable {
+    public var provider: String?
+
+    enum CodingKeys: String, CodingKey {
+        case provider = "provider"
+    }
+}
+
+public enum ProfileLastLogin: Codable {
+    case password(ProfileLastLoginPassword)
+    case sso(SingleSignOn)
+
+    enum CodingKeys: String, CodingKey {
+        case tag = "method"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .tag)
+        switch tag {
+        case "password":
+            self = .password(try ProfileLastLoginPassword(from: decoder))
+        case "sso":
+            self = .sso(try SingleSignOn(from: decoder))
+        default:
+            throw DecodingError.dataCorruptedError(
+                forKey: .tag,
+                in: container,
+                debugDescription: "unknown discriminator tag value: \(tag)"
+            )
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .password(let value):
+            try container.encode("password", forKey: .tag)
+            try value.encode(to: encoder)
+        case .sso(let value):
+            try container.encode("sso", forKey: .tag)
+            try value.encode(to: encoder)
+        }
+    }
+}
+
+public struct ProfilePreferences: Codable {
+    public var theme: String
+
+    enum CodingKeys: String, CodingKey {
+        case theme = "theme"
+    }
+}
+
+public struct Profile: Codable {
+    public var address: ProfileAddress?
+    public var id: String
+    public var lastLogin: ProfileLastLogin?
+    public var name: Name?
+    public var nickname: String?
+    public var scores: [String: Double?]
+    public var status: AccountStatus?
+    public var tags: [String?]?
+    public var age: UInt32?
+    public var anything: JSONValue?
+    public var friends: [Name?]?
+    public var middleName: String?
+    public var preferences: ProfilePreferences?
+    public var previousStatus: AccountStatus?
+
+    enum CodingKeys: String, CodingKey {
+        case address = "address"
+        case id = "id"
+        case lastLogin = "lastLogin"
+        case name = "name"
+        case nickname = "nickname"
+        case scores = "scores"
+        case status = "status"
+        case tags = "tags"
+        case age = "age"
+        case anything = "anything"
+        case friends = "friends"
+        case middleName = "middleName"
+        case preferences = "preferences"
+        case previousStatus = "previousStatus"
+    }
+}
+
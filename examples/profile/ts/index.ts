@@ -0,0 +1,62 @@
+// Code generated by jddf-codegen. DO NOT EDIT.
+//
+// Source: examples/profile/profile.jddf.json
+// Version: 0.1.0
+
+export type Name = string;
+
+/**
+ * Whether the account is in use.
+ *
+ * - "ACTIVE": The user has signed in recently.
+ * - "INACTIVE": The user has not signed in for a year or more.
+ */
+export type AccountStatus = "ACTIVE" | "INACTIVE";
+
+export interface ProfileAddress {
+  street: string;
+}
+
+export interface ProfileLastLoginPassword {
+  method: "password";
+}
+
+/** Signed in through an identity provider. */
+export interface SingleSignOn {
+  method: "sso";
+  provider: string | null;
+}
+
+/** How the user last signed in, if they ever have. */
+export type ProfileLastLogin = ProfileLastLoginPassword | SingleSignOn;
+
+export interface ProfilePreferences {
+  theme: string;
+  [key: string]: unknown;
+}
+
+/** A user's public profile. */
+export interface Profile {
+  address: ProfileAddress | null;
+  /** A unique, opaque identifier for the user. */
+  id: string;
+  /** How the user last signed in, if they ever have. */
+  lastLogin: ProfileLastLogin | null;
+  name: Name | null;
+  /**
+   * What the user would like to be called.
+   *
+   * Null if they have chosen not to say.
+   */
+  nickname: string | null;
+  scores: { [name: string]: number | null};
+  status: AccountStatus | null;
+  tags: (string | null)[] | null;
+  age?: number;
+  anything?: any;
+  friends?: (Name | null)[] | null;
+  middleName?: string | null;
+  preferences?: ProfilePreferences;
+  previousStatus?: AccountStatus | null;
+}
+
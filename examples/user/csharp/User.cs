@@ -0,0 +1,21 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example.User;
+
+public record User
+{
+    [JsonPropertyName("favoriteNumbers")]
+    public List<int> FavoriteNumbers { get; init; } = default!;
+
+    [JsonPropertyName("id")]
+    public string Id { get; init; } = default!;
+
+    [JsonPropertyName("name")]
+    public string Name { get; init; } = default!;
+}
+
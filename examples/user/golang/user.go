@@ -1,11 +1,138 @@
-package golang
-import "time"
-import "encoding/json"
-import "errors"
-var ErrUnknownVariant = errors.New("golang: unknown discriminator tag value")
+// Code generated by jddf-codegen. DO NOT EDIT.
+//
+// Source: examples/user/user.jddf.json
+// Version: 0.1.0
+
+package user
+
+import (
+	"encoding/json"
+	"fmt"
+	"strconv"
+	"strings"
+)
+
+// ValidationError is a way in which a value does not satisfy the schema.
+// Both paths are JSON Pointers: InstancePath to the part of the value at
+// fault, and SchemaPath to the part of the schema it does not satisfy.
+type ValidationError struct {
+	InstancePath string
+	SchemaPath   string
+}
+
+func (e ValidationError) Error() string {
+	return fmt.Sprintf("user: value at %q does not satisfy schema at %q", e.InstancePath, e.SchemaPath)
+}
+
+// ValidationErrors is every way in which a value does not satisfy the schema.
+//
+// A struct checks the integers it holds as it is decoded, and reports any
+// which are out of range, or are not integers, among these. An integer
+// held by no struct is decoded by encoding/json alone, which fails to
+// decode it in those cases, and also where it is written as JDDF allows
+// but encoding/json does not, such as 1.0.
+type ValidationErrors []ValidationError
+
+func (e ValidationErrors) Error() string {
+	msgs := make([]string, len(e))
+	for i, err := range e {
+		msgs[i] = err.Error()
+	}
+	return strings.Join(msgs, "; ")
+}
+
+// decodeInteger returns the integer a JSON value is, if it is one within
+// [min, max]. Unlike encoding/json, JDDF allows an integer to be written
+// with a fractional part or exponent, such as 1.0 or 1e2.
+func decodeInteger(b json.RawMessage, min, max float64) (int64, bool) {
+	f, err := strconv.ParseFloat(string(b), 64)
+	if err != nil || f < min || f > max || f != float64(int64(f)) {
+		return 0, false
+	}
+	return int64(f), true
+}
+
 type User struct {
-	Id string `json:"id"`
-	Name string `json:"name"`
 	FavoriteNumbers []int32 `json:"favoriteNumbers"`
+	Id              string  `json:"id"`
+	Name            string  `json:"name"`
+
+	// the schema paths, relative to the struct's own, of the required
+	// properties which were absent from the JSON it was decoded from
+	missing []string
+
+	// the ways in which the integers in the JSON it was decoded from did not
+	// satisfy the schema, with instance paths relative to the struct's own
+	invalid []ValidationError
 }
 
+func (v *User) UnmarshalJSON(b []byte) error {
+	var obj map[string]json.RawMessage
+	if err := json.Unmarshal(b, &obj); err != nil {
+		return err
+	}
+	for key := range obj {
+		switch key {
+		case "favoriteNumbers", "id", "name":
+		default:
+			return fmt.Errorf("user: unknown property %q in User", key)
+		}
+	}
+
+	v.missing = nil
+	if _, ok := obj["favoriteNumbers"]; !ok {
+		v.missing = append(v.missing, "/properties/favoriteNumbers")
+	}
+	if _, ok := obj["id"]; !ok {
+		v.missing = append(v.missing, "/properties/id")
+	}
+	if _, ok := obj["name"]; !ok {
+		v.missing = append(v.missing, "/properties/name")
+	}
+
+	type alias User
+	var numbers struct {
+		alias
+		FavoriteNumbers []json.RawMessage `json:"favoriteNumbers"`
+	}
+	numbers.alias = alias(*v)
+	if err := json.Unmarshal(b, &numbers); err != nil {
+		return err
+	}
+	*v = User(numbers.alias)
+
+	v.invalid = nil
+	if numbers.FavoriteNumbers == nil {
+		v.FavoriteNumbers = nil
+	} else {
+		v.FavoriteNumbers = make([]int32, len(numbers.FavoriteNumbers))
+		for i, x := range numbers.FavoriteNumbers {
+			if n, ok := decodeInteger(x, -2147483648, 2147483647); ok {
+				v.FavoriteNumbers[i] = int32(n)
+			} else {
+				v.invalid = append(v.invalid, ValidationError{InstancePath: "/favoriteNumbers/" + fmt.Sprint(i), SchemaPath: "/properties/favoriteNumbers/elements/type"})
+			}
+		}
+	}
+	return nil
+}
+
+// Validate reports every way in which v does not satisfy the schema, as
+// ValidationErrors, or returns nil if there are none.
+func (v User) Validate() error {
+	var errs ValidationErrors
+	v.validate("", &errs)
+	if len(errs) != 0 {
+		return errs
+	}
+	return nil
+}
+
+func (v User) validate(path string, errs *ValidationErrors) {
+	for _, missing := range v.missing {
+		*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: missing})
+	}
+	for _, invalid := range v.invalid {
+		*errs = append(*errs, ValidationError{InstancePath: path + invalid.InstancePath, SchemaPath: invalid.SchemaPath})
+	}
+}
@@ -0,0 +1,42 @@
+package com.example.user;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.List;
+
+public class User {
+    @JsonProperty("favoriteNumbers")
+    private List<Integer> favoriteNumbers;
+
+    @JsonProperty("id")
+    private String id;
+
+    @JsonProperty("name")
+    private String name;
+
+    public User() {
+    }
+
+    public List<Integer> getFavoriteNumbers() {
+        return favoriteNumbers;
+    }
+
+    public void setFavoriteNumbers(List<Integer> favoriteNumbers) {
+        this.favoriteNumbers = favoriteNumbers;
+    }
+
+    public String getId() {
+        return id;
+    }
+
+    public void setId(String id) {
+        this.id = id;
+    }
+
+    public String getName() {
+        return name;
+    }
+
+    public void setName(String name) {
+        this.name = name;
+    }
+}
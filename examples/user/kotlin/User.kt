@@ -0,0 +1,15 @@
+package com.example.user
+
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+
+@Serializable
+data class User(
+    @SerialName("favoriteNumbers")
+    val favoriteNumbers: List<Int>,
+    @SerialName("id")
+    val id: String,
+    @SerialName("name")
+    val name: String,
+)
+
@@ -0,0 +1,35 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from datetime import datetime
+from enum import Enum
+from typing import Any, Dict, List, Optional
+
+
+def _parse_timestamp(value: str) -> datetime:
+    # datetime.fromisoformat does not understand the "Z" suffix.
+    return datetime.fromisoformat(value.replace("Z", "+00:00"))
+
+
+@dataclass
+class User:
+    favorite_numbers: List[int]
+    id: str
+    name: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> User:
+        return cls(
+            favorite_numbers=[v0 for v0 in data["favoriteNumbers"]],
+            id=data["id"],
+            name=data["name"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["favoriteNumbers"] = [v0 for v0 in self.favorite_numbers]
+        data["id"] = self.id
+        data["name"] = self.name
+        return data
+
+
@@ -0,0 +1,14 @@
+import Foundation
+
+public struct User: Codable {
+    public var favoriteNumbers: [Int32]
+    public var id: String
+    public var name: String
+
+    enum CodingKeys: String, CodingKey {
+        case favoriteNumbers = "favoriteNumbers"
+        case id = "id"
+        case name = "name"
+    }
+}
+
@@ -1,6 +1,11 @@
+// Code generated by jddf-codegen. DO NOT EDIT.
+//
+// Source: examples/user/user.jddf.json
+// Version: 0.1.0
+
 export interface User {
-  id: string;
   favoriteNumbers: number[];
+  id: string;
   name: string;
 }
 
@@ -0,0 +1,796 @@
+use failure::{format_err, Error};
+use inflector::Inflector;
+use jddf::{Form, Schema};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// A language-neutral representation of the types a schema describes.
+///
+/// The IR is produced once from a root schema, and is then consumed by each of
+/// the targets. Naming, hoisting of nested schemas into types of their own, and
+/// the handling of discriminators all happen here, so that every target agrees
+/// on what types exist and what they are called.
+#[derive(Debug)]
+pub struct Ir {
+    /// Every named type, in the order they should be declared in.
+    ///
+    /// Types hoisted out of a schema come before the type of the schema
+    /// itself. Definitions, properties, enum values and discriminator variants
+    /// are each visited in sorted order, so the IR for a given schema is always
+    /// the same.
+    pub decls: Vec<Decl>,
+}
+
+/// A named type.
+#[derive(Debug)]
+pub struct Decl {
+    /// The type's name, in PascalCase.
+    pub name: String,
+
+    /// The `description` from the schema's metadata, if it has one.
+    pub description: Option<String>,
+
+    /// The JSON Pointer to the schema the type is declared for, as it appears
+    /// in the `schemaPath` of a JDDF validation error.
+    pub schema_path: String,
+
+    /// What sort of type this is.
+    pub kind: DeclKind,
+}
+
+#[derive(Debug)]
+pub enum DeclKind {
+    /// An object with a fixed set of properties.
+    Struct(Struct),
+
+    /// A string taking on one of a fixed set of values.
+    Enum(Vec<EnumValue>),
+
+    /// An object whose properties depend on the value of a tag property.
+    Discriminator(Discriminator),
+
+    /// Another name for a type which does not need a declaration of its own.
+    Alias(Type),
+}
+
+#[derive(Debug)]
+pub struct Struct {
+    pub fields: Vec<Field>,
+
+    /// Whether the object may have properties besides those in `fields`.
+    ///
+    /// For a discriminator variant, the tag is never counted as one of these.
+    pub allow_additional: bool,
+}
+
+#[derive(Debug)]
+pub struct Field {
+    /// The name of the property, as it appears in JSON.
+    pub json: String,
+
+    /// Whether the property must always be present.
+    pub required: bool,
+
+    /// The `description` from the property's metadata, if it has one.
+    pub description: Option<String>,
+
+    pub ty: Type,
+}
+
+#[derive(Debug)]
+pub struct EnumValue {
+    /// The value, as it appears in JSON.
+    pub json: String,
+
+    /// The value's entry in the `enumDescriptions` of the enum's metadata, if
+    /// it has one.
+    pub description: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Discriminator {
+    /// The name of the tag property, as it appears in JSON.
+    pub tag: String,
+
+    pub variants: Vec<Variant>,
+}
+
+#[derive(Debug)]
+pub struct Variant {
+    /// The name of the type holding the variant's properties, in PascalCase.
+    pub name: String,
+
+    /// The value the tag takes on for this variant, as it appears in JSON.
+    pub tag_value: String,
+
+    /// The `description` from the variant's metadata, if it has one.
+    pub description: Option<String>,
+
+    /// The JSON Pointer to the variant's schema, as for `Decl::schema_path`.
+    pub schema_path: String,
+
+    /// The properties of the variant, excluding the tag.
+    pub body: Struct,
+}
+
+/// A reference to a type, as it would appear in a property or element.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    Any,
+    Boolean,
+    String,
+    Timestamp,
+    Int8,
+    Uint8,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Float32,
+    Float64,
+    Elements(Box<Type>),
+    Values(Box<Type>),
+    Ref(String),
+
+    /// A value which may also be JSON null.
+    ///
+    /// This is independent of whether a property may be absent, which is
+    /// tracked by `Field::required`. It never wraps `Any`, which admits null
+    /// already, nor another `Nullable`.
+    Nullable(Box<Type>),
+
+    /// A type which some targets replace with one named in the schema's
+    /// metadata. Targets without a replacement use the wrapped type.
+    Custom(Box<Type>, Custom),
+}
+
+impl Type {
+    /// The name of the type this one refers to, once any elements, values,
+    /// nullability or replacement around it are stripped away.
+    fn referenced(&self) -> Option<&str> {
+        match self {
+            Type::Elements(ty) | Type::Values(ty) | Type::Nullable(ty) | Type::Custom(ty, _) => {
+                ty.referenced()
+            }
+            Type::Ref(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// The name of the type this one refers to, if a value of this type holds
+    /// one of that type directly, rather than among elements or values.
+    pub fn held_directly(&self) -> Option<&str> {
+        match self {
+            Type::Nullable(ty) | Type::Custom(ty, _) => ty.held_directly(),
+            Type::Ref(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+/// Replacements for a type, from the `goType`, `goImport` and `tsType` keys of
+/// a schema's metadata.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Custom {
+    /// The Go type to use, such as `uuid.UUID`.
+    pub go_type: Option<String>,
+
+    /// The package `go_type` comes from, such as `github.com/google/uuid`.
+    pub go_import: Option<String>,
+
+    /// The TypeScript type to use.
+    pub ts_type: Option<String>,
+}
+
+impl Ir {
+    /// Construct the IR for a root schema.
+    ///
+    /// `root_name` is the name given to the type corresponding to the root
+    /// schema itself.
+    ///
+    /// Fails if two different parts of the schema would be given the same
+    /// name, if the schema's metadata is malformed, or if `root_name` could not
+    /// be made into an identifier.
+    pub fn from_schema(root_name: &str, schema: &Schema) -> Result<Ir, Error> {
+        // Anything else would either be lost, or mangled, in converting the
+        // name to each target's case.
+        let mut chars = root_name.chars();
+        if !chars.next().is_some_and(char::is_alphabetic)
+            || !chars.all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(format_err!(
+                "Root name {:?} must start with a letter, and contain only letters, digits, underscores and hyphens",
+                root_name
+            ));
+        }
+
+        let mut builder = Builder {
+            decls: Vec::new(),
+            names: BTreeMap::new(),
+            nullable_defs: nullable_definitions(schema),
+            def_names: HashMap::new(),
+        };
+
+        if let Some(defs) = schema.definitions() {
+            for (name, schema) in defs {
+                let pointer = ["definitions", name.as_str()];
+                let type_name = type_name(schema, &pointer)?.unwrap_or(name);
+                builder
+                    .def_names
+                    .insert(name.clone(), self::name(&[type_name]));
+            }
+
+            for (name, schema) in sorted(defs) {
+                let mut pointer = vec!["definitions", name.as_str()];
+                let ty = builder.transform(&mut vec![name], &mut pointer, schema)?;
+
+                // A type name from the schema's metadata has been declared
+                // already, by transform.
+                if type_name(schema, &pointer)?.is_none() {
+                    builder.ensure_has_name(&[name], &pointer, schema, ty);
+                }
+            }
+        }
+
+        let ty = builder.transform(&mut vec![root_name], &mut vec![], schema)?;
+        if type_name(schema, &[])?.is_none() {
+            builder.ensure_has_name(&[root_name], &[], schema, ty);
+        }
+
+        builder.check_names()?;
+
+        Ok(Ir {
+            decls: builder.decls,
+        })
+    }
+
+    /// Whether the named type is an alias which refers back to itself, either
+    /// directly or by way of other aliases, such as a map of itself.
+    ///
+    /// Targets whose type aliases must not be recursive should declare these as
+    /// types of their own instead.
+    pub fn is_recursive_alias(&self, name: &str) -> bool {
+        let alias = |name: &str| {
+            self.decls.iter().find_map(|decl| match &decl.kind {
+                DeclKind::Alias(ty) if decl.name == name => Some(ty),
+                _ => None,
+            })
+        };
+
+        let mut seen = HashSet::new();
+        let mut next = alias(name);
+        while let Some(ty) = next {
+            let target = match ty.referenced() {
+                Some(target) => target,
+                None => return false,
+            };
+
+            if target == name {
+                return true;
+            }
+
+            next = if seen.insert(target) {
+                alias(target)
+            } else {
+                None
+            };
+        }
+
+        false
+    }
+
+    /// Whether a value of the type named `from` may hold a value of the type
+    /// named `to` directly: as a property, possibly a nullable one, or as the
+    /// variant of a discriminator, rather than among elements or values.
+    ///
+    /// Discriminator variants count as types of their own, by
+    /// `Variant::name`. Targets which lay values out inline, such that a type
+    /// cannot directly hold itself, should box a property whose type holds the
+    /// type the property belongs to.
+    pub fn holds_directly(&self, from: &str, to: &str) -> bool {
+        let mut seen = HashSet::new();
+        let mut stack = vec![from];
+        while let Some(name) = stack.pop() {
+            if name == to {
+                return true;
+            }
+
+            if seen.insert(name) {
+                stack.extend(self.held_directly(name));
+            }
+        }
+
+        false
+    }
+
+    // The types a value of the named type holds directly, as for
+    // holds_directly.
+    fn held_directly<'a>(&'a self, name: &str) -> Vec<&'a str> {
+        let fields = |fields: &'a [Field]| {
+            fields
+                .iter()
+                .filter_map(|field| field.ty.held_directly())
+                .collect()
+        };
+
+        for decl in &self.decls {
+            match &decl.kind {
+                DeclKind::Struct(Struct { fields: f, .. }) if decl.name == name => {
+                    return fields(f);
+                }
+                DeclKind::Alias(ty) if decl.name == name => {
+                    return ty.held_directly().into_iter().collect();
+                }
+                DeclKind::Discriminator(Discriminator { variants, .. }) => {
+                    if decl.name == name {
+                        return variants.iter().map(|v| v.name.as_str()).collect();
+                    }
+                    if let Some(variant) = variants.iter().find(|v| v.name == name) {
+                        return fields(&variant.body.fields);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Vec::new()
+    }
+}
+
+/// The name of a constant belonging to a type, such as a member of an enum.
+///
+/// Targets which declare such constants alongside other types, rather than
+/// scoped within the type they belong to, should use this to name them. These
+/// names are checked for collisions along with the names of types.
+pub fn member_name(parent: &str, member: &str) -> String {
+    name(&[parent, member])
+}
+
+struct Builder {
+    decls: Vec<Decl>,
+
+    // Every name handed out so far, and the schema paths it was handed out
+    // for. Any name with more than one path is a collision.
+    names: BTreeMap<String, Vec<String>>,
+
+    // The definitions which may be null, and so which make any reference to
+    // them nullable too.
+    nullable_defs: HashSet<String>,
+
+    // The name of the type declared for each definition, which may come from
+    // its metadata rather than the definition's own name.
+    def_names: HashMap<String, String>,
+}
+
+impl Builder {
+    // `path` is what the names of hoisted types are derived from. `pointer` is
+    // the JSON Pointer to the schema being transformed, used in error messages.
+    fn transform<'a>(
+        &mut self,
+        path: &mut Vec<&'a str>,
+        pointer: &mut Vec<&'a str>,
+        schema: &'a Schema,
+    ) -> Result<Type, Error> {
+        let mut ty = match type_name(schema, pointer)? {
+            // A type named in the schema's metadata takes the place of the one
+            // which would have been named after its path, and the types
+            // hoisted out of it are named after it in turn.
+            Some(type_name) => {
+                let ty = self.transform_form(&mut vec![type_name], pointer, schema)?;
+                let is_nullable_ref = matches!(ty, Type::Nullable(_));
+                self.ensure_has_name(&[type_name], pointer, schema, ty);
+
+                let ty = Type::Ref(name(&[type_name]));
+                if is_nullable_ref {
+                    nullable(ty)
+                } else {
+                    ty
+                }
+            }
+            None => self.transform_form(path, pointer, schema)?,
+        };
+
+        let custom = Custom {
+            go_type: metadata_str(schema, "goType", pointer)?.map(str::to_owned),
+            go_import: metadata_str(schema, "goImport", pointer)?.map(str::to_owned),
+            ts_type: metadata_str(schema, "tsType", pointer)?.map(str::to_owned),
+        };
+        if custom.go_type.is_some() || custom.ts_type.is_some() {
+            ty = Type::Custom(Box::new(ty), custom);
+        } else if custom.go_import.is_some() {
+            return Err(format_err!(
+                "{}: metadata.goImport is only allowed alongside metadata.goType",
+                to_pointer(pointer)
+            ));
+        }
+
+        if is_nullable(schema) {
+            Ok(nullable(ty))
+        } else {
+            Ok(ty)
+        }
+    }
+
+    fn transform_form<'a>(
+        &mut self,
+        path: &mut Vec<&'a str>,
+        pointer: &mut Vec<&'a str>,
+        schema: &'a Schema,
+    ) -> Result<Type, Error> {
+        Ok(match schema.form() {
+            Form::Empty => Type::Any,
+            Form::Ref(def) if self.nullable_defs.contains(def) => {
+                nullable(Type::Ref(self.def_names[def].clone()))
+            }
+            Form::Ref(def) => Type::Ref(self.def_names[def].clone()),
+            Form::Type(jddf::Type::Boolean) => Type::Boolean,
+            Form::Type(jddf::Type::String) => Type::String,
+            Form::Type(jddf::Type::Timestamp) => Type::Timestamp,
+            Form::Type(jddf::Type::Int8) => Type::Int8,
+            Form::Type(jddf::Type::Uint8) => Type::Uint8,
+            Form::Type(jddf::Type::Int16) => Type::Int16,
+            Form::Type(jddf::Type::Uint16) => Type::Uint16,
+            Form::Type(jddf::Type::Int32) => Type::Int32,
+            Form::Type(jddf::Type::Uint32) => Type::Uint32,
+            Form::Type(jddf::Type::Float32) => Type::Float32,
+            Form::Type(jddf::Type::Float64) => Type::Float64,
+            Form::Enum(vals) => {
+                let mut vals: Vec<_> = vals.iter().collect();
+                vals.sort();
+
+                let enum_name = name(path);
+                for val in &vals {
+                    let pointer = format!("{} ({:?})", to_pointer(pointer), val);
+                    self.register(member_name(&enum_name, val), pointer.clone());
+
+                    // Targets which scope members within their enum name them
+                    // as they would properties, and so like properties they
+                    // are also checked alongside each other alone.
+                    self.register(format!("{}.{}", enum_name, name(&[val])), pointer);
+                }
+
+                let values = vals
+                    .into_iter()
+                    .map(|val| EnumValue {
+                        json: val.to_owned(),
+                        description: enum_description(schema, val),
+                    })
+                    .collect();
+
+                self.declare(path, pointer, schema, DeclKind::Enum(values))
+            }
+            Form::Elements(schema) => {
+                pointer.push("elements");
+                let ty = self.transform(path, pointer, schema)?;
+                pointer.pop();
+
+                Type::Elements(Box::new(ty))
+            }
+            Form::Properties {
+                required,
+                optional,
+                allow_additional,
+                ..
+            } => {
+                let body =
+                    self.transform_struct(path, pointer, required, optional, *allow_additional)?;
+                self.declare(path, pointer, schema, DeclKind::Struct(body))
+            }
+            Form::Discriminator(tag, mapping) => {
+                let disc_name = name(path);
+                let tag_enum_name = member_name(&disc_name, tag);
+
+                pointer.push("discriminator");
+                self.register(tag_enum_name.clone(), to_pointer(pointer));
+
+                let mut variants = Vec::new();
+                for (tag_value, variant) in sorted(mapping) {
+                    pointer.push("mapping");
+                    pointer.push(tag_value);
+
+                    let mut path = match type_name(variant, pointer)? {
+                        Some(type_name) => vec![type_name],
+                        None => {
+                            let mut path = path.clone();
+                            path.push(tag_value);
+                            path
+                        }
+                    };
+
+                    // We can count on the variant being of the properties form.
+                    if let Form::Properties {
+                        required,
+                        optional,
+                        allow_additional,
+                        ..
+                    } = variant.form()
+                    {
+                        let tag_pointer = format!("{} ({:?})", to_pointer(pointer), tag_value);
+                        self.register(name(&path), to_pointer(pointer));
+                        self.register(member_name(&tag_enum_name, tag_value), tag_pointer.clone());
+
+                        // Just as for the members of an enum.
+                        self.register(format!("{}.{}", disc_name, name(&[tag_value])), tag_pointer);
+
+                        variants.push(Variant {
+                            name: name(&path),
+                            tag_value: tag_value.to_owned(),
+                            description: description(variant),
+                            schema_path: schema_path(pointer),
+                            body: self.transform_struct(
+                                &mut path,
+                                pointer,
+                                required,
+                                optional,
+                                *allow_additional,
+                            )?,
+                        });
+                    }
+
+                    pointer.pop();
+                    pointer.pop();
+                }
+
+                pointer.pop();
+
+                let discriminator = Discriminator {
+                    tag: tag.to_owned(),
+                    variants,
+                };
+
+                self.declare(
+                    path,
+                    pointer,
+                    schema,
+                    DeclKind::Discriminator(discriminator),
+                )
+            }
+            Form::Values(schema) => {
+                pointer.push("values");
+                let ty = self.transform(path, pointer, schema)?;
+                pointer.pop();
+
+                Type::Values(Box::new(ty))
+            }
+        })
+    }
+
+    fn transform_struct<'a>(
+        &mut self,
+        path: &mut Vec<&'a str>,
+        pointer: &mut Vec<&'a str>,
+        required: &'a HashMap<String, Schema>,
+        optional: &'a HashMap<String, Schema>,
+        allow_additional: bool,
+    ) -> Result<Struct, Error> {
+        let required = sorted(required)
+            .into_iter()
+            .map(|prop| (prop, true, "properties"));
+        let optional = sorted(optional)
+            .into_iter()
+            .map(|prop| (prop, false, "optionalProperties"));
+
+        let mut fields = Vec::new();
+        for ((prop, schema), is_required, keyword) in required.chain(optional) {
+            path.push(prop);
+            pointer.push(keyword);
+            pointer.push(prop);
+            let ty = self.transform(path, pointer, schema)?;
+
+            // Properties whose names differ only in case or punctuation would
+            // be given the same identifier in every target, as would those
+            // which differ only in a leading "x" once the targets have made
+            // them start with a letter. Since they only conflict with each
+            // other, they are qualified by the name of the type they belong to.
+            path.pop();
+            self.register(
+                format!("{}.{}", name(path), name(&[prop])),
+                to_pointer(pointer),
+            );
+            pointer.pop();
+            pointer.pop();
+
+            fields.push(Field {
+                json: prop.to_owned(),
+                required: is_required,
+                description: description(schema),
+                ty,
+            });
+        }
+
+        Ok(Struct {
+            fields,
+            allow_additional,
+        })
+    }
+
+    fn declare(
+        &mut self,
+        path: &[&str],
+        pointer: &[&str],
+        schema: &Schema,
+        kind: DeclKind,
+    ) -> Type {
+        let name = name(path);
+        self.register(name.clone(), to_pointer(pointer));
+        self.decls.push(Decl {
+            name: name.clone(),
+            description: description(schema),
+            schema_path: schema_path(pointer),
+            kind,
+        });
+
+        Type::Ref(name)
+    }
+
+    /// Ensure that a top-level schema -- the root or a definition -- has a type
+    /// declared under its own name, so that it can be referred to.
+    ///
+    /// The declared type itself is never nullable. For definitions, references
+    /// to them are made nullable instead.
+    fn ensure_has_name(&mut self, path: &[&str], pointer: &[&str], schema: &Schema, ty: Type) {
+        let ty = match ty {
+            Type::Nullable(ty) => *ty,
+            _ => ty,
+        };
+
+        if ty != Type::Ref(name(path)) {
+            self.register(name(path), to_pointer(pointer));
+            self.decls.push(Decl {
+                name: name(path),
+                description: description(schema),
+                schema_path: schema_path(pointer),
+                kind: DeclKind::Alias(ty),
+            });
+        }
+    }
+
+    fn register(&mut self, name: String, pointer: String) {
+        self.names.entry(name).or_default().push(pointer);
+    }
+
+    fn check_names(&self) -> Result<(), Error> {
+        let collisions: Vec<_> = self
+            .names
+            .iter()
+            .filter(|(_, pointers)| pointers.len() > 1)
+            .map(|(name, pointers)| format!("  {}: {}", name, pointers.join(", ")))
+            .collect();
+
+        if collisions.is_empty() {
+            Ok(())
+        } else {
+            Err(format_err!(
+                "Schema would generate the same name for different things:\n{}",
+                collisions.join("\n")
+            ))
+        }
+    }
+}
+
+fn description(schema: &Schema) -> Option<String> {
+    let description = schema.extra().get("metadata")?.get("description")?;
+    description.as_str().map(str::to_owned)
+}
+
+fn enum_description(schema: &Schema, value: &str) -> Option<String> {
+    let descriptions = schema.extra().get("metadata")?.get("enumDescriptions")?;
+    descriptions.get(value)?.as_str().map(str::to_owned)
+}
+
+fn type_name<'a>(schema: &'a Schema, pointer: &[&str]) -> Result<Option<&'a str>, Error> {
+    match metadata_str(schema, "typeName", pointer)? {
+        Some("") => Err(format_err!(
+            "{}: metadata.typeName must not be empty",
+            to_pointer(pointer)
+        )),
+        type_name => Ok(type_name),
+    }
+}
+
+fn metadata_str<'a>(
+    schema: &'a Schema,
+    key: &str,
+    pointer: &[&str],
+) -> Result<Option<&'a str>, Error> {
+    let value = match schema.extra().get("metadata").and_then(|m| m.get(key)) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    match value.as_str() {
+        Some(s) => Ok(Some(s)),
+        None => Err(format_err!(
+            "{}: metadata.{} must be a string",
+            to_pointer(pointer),
+            key
+        )),
+    }
+}
+
+fn is_nullable(schema: &Schema) -> bool {
+    schema.extra().get("nullable") == Some(&Value::Bool(true))
+}
+
+fn nullable(ty: Type) -> Type {
+    match ty {
+        Type::Any | Type::Nullable(_) => ty,
+        _ => Type::Nullable(Box::new(ty)),
+    }
+}
+
+/// Find the definitions which may be null, either because they are marked
+/// nullable themselves or because they refer to a definition which may be.
+fn nullable_definitions(schema: &Schema) -> HashSet<String> {
+    let defs = match schema.definitions() {
+        Some(defs) => defs,
+        None => return HashSet::new(),
+    };
+
+    let mut out = HashSet::new();
+    for (name, mut def) in defs {
+        // Follow the chain of references, taking care not to loop forever on
+        // definitions which refer to one another.
+        let mut seen = HashSet::new();
+        while seen.insert(def as *const Schema) {
+            if is_nullable(def) {
+                out.insert(name.clone());
+                break;
+            }
+
+            match def.form() {
+                Form::Ref(next) => def = &defs[next],
+                _ => break,
+            }
+        }
+    }
+
+    out
+}
+
+/// Iterate over a map in order of its keys.
+///
+/// The maps in a schema are hash maps, and so have no meaningful order of their
+/// own. Sorting them keeps generated code from changing between runs.
+fn sorted<V>(map: &HashMap<String, V>) -> BTreeMap<&String, &V> {
+    map.iter().collect()
+}
+
+/// Capitalized keywords in one or more targets, which cannot be used as type
+/// names as-is: `None`, `True` and `False` in Python, `Self` in Rust and Swift,
+/// and `Any`, `Protocol` and `Type` in Swift.
+const RESERVED_NAMES: &[&str] = &["Any", "False", "None", "Protocol", "Self", "True", "Type"];
+
+fn name(path: &[&str]) -> String {
+    let name = path.join("_").to_pascal_case();
+
+    // PascalCase names never contain underscores of their own, so suffixing a
+    // reserved name with one cannot make it collide with another name.
+    match name.chars().next() {
+        Some(c) if c.is_alphabetic() => {
+            if RESERVED_NAMES.contains(&name.as_ref()) {
+                format!("{}_", name)
+            } else {
+                name
+            }
+        }
+        _ => format!("X{}", name),
+    }
+}
+
+fn to_pointer(pointer: &[&str]) -> String {
+    format!("#{}", schema_path(pointer))
+}
+
+fn schema_path(pointer: &[&str]) -> String {
+    pointer
+        .iter()
+        .map(|token| format!("/{}", pointer_token(token)))
+        .collect()
+}
+
+/// Escape a property name or other string for use as one of the tokens of a
+/// JSON Pointer.
+pub fn pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
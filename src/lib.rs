@@ -0,0 +1,127 @@
+//! Generate data structures from JDDF schemas.
+//!
+//! This crate powers the `jddf-codegen` command-line tool, and can also be
+//! used directly, for instance from a `build.rs` script:
+//!
+//! ```
+//! use jddf::{Schema, SerdeSchema};
+//! use jddf_codegen::targets::typescript;
+//! use jddf_codegen::Options;
+//!
+//! let serde_schema: SerdeSchema = serde_json::from_str(
+//!     r#"{ "properties": { "name": { "type": "string" }}}"#,
+//! ).unwrap();
+//! let schema = Schema::from_serde(serde_schema).unwrap();
+//!
+//! let mut options = Options::new("user");
+//! options.typescript = Some(typescript::Options::new("src/generated"));
+//!
+//! for (path, contents) in jddf_codegen::generate(&schema, &options).unwrap() {
+//!     println!("{}:\n{}", path.display(), contents);
+//! }
+//! ```
+
+mod ir;
+mod target;
+pub mod targets;
+
+use failure::Error;
+use ir::Ir;
+use jddf::Schema;
+use std::path::PathBuf;
+use target::Target;
+use targets::{csharp, golang, java, kotlin, python, rust, swift, typescript};
+
+/// Which targets to generate code for, and how.
+///
+/// A target is only generated if its options are set.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Options {
+    /// The name of the schema, from which the name of the type generated for
+    /// the root schema is derived.
+    pub root_name: String,
+
+    pub typescript: Option<typescript::Options>,
+    pub golang: Option<golang::Options>,
+    pub rust: Option<rust::Options>,
+    pub python: Option<python::Options>,
+    pub java: Option<java::Options>,
+    pub kotlin: Option<kotlin::Options>,
+    pub swift: Option<swift::Options>,
+    pub csharp: Option<csharp::Options>,
+}
+
+impl Options {
+    /// Construct options for a schema named `root_name`, with no targets
+    /// enabled.
+    pub fn new(root_name: &str) -> Options {
+        Options {
+            root_name: root_name.to_owned(),
+            typescript: None,
+            golang: None,
+            rust: None,
+            python: None,
+            java: None,
+            kotlin: None,
+            swift: None,
+            csharp: None,
+        }
+    }
+}
+
+/// Generate code for a schema.
+///
+/// Returns the path and contents of each generated file. Nothing is written to
+/// disk. If any of the targets fails, no files are returned at all.
+pub fn generate(schema: &Schema, options: &Options) -> Result<Vec<(PathBuf, String)>, Error> {
+    // Build up the intermediate representation all the targets work off of.
+    let ir = Ir::from_schema(&options.root_name, schema)?;
+
+    let mut files = Vec::new();
+    let root_name = &options.root_name;
+
+    if let Some(ref opts) = options.typescript {
+        files.extend(generate_target::<typescript::Target>(root_name, opts, &ir)?);
+    }
+
+    if let Some(ref opts) = options.golang {
+        files.extend(generate_target::<golang::Target>(root_name, opts, &ir)?);
+    }
+
+    if let Some(ref opts) = options.rust {
+        files.extend(generate_target::<rust::Target>(root_name, opts, &ir)?);
+    }
+
+    if let Some(ref opts) = options.python {
+        files.extend(generate_target::<python::Target>(root_name, opts, &ir)?);
+    }
+
+    if let Some(ref opts) = options.java {
+        files.extend(generate_target::<java::Target>(root_name, opts, &ir)?);
+    }
+
+    if let Some(ref opts) = options.kotlin {
+        files.extend(generate_target::<kotlin::Target>(root_name, opts, &ir)?);
+    }
+
+    if let Some(ref opts) = options.swift {
+        files.extend(generate_target::<swift::Target>(root_name, opts, &ir)?);
+    }
+
+    if let Some(ref opts) = options.csharp {
+        files.extend(generate_target::<csharp::Target>(root_name, opts, &ir)?);
+    }
+
+    Ok(files)
+}
+
+fn generate_target<T: Target>(
+    root_name: &str,
+    options: &T::Options,
+    ir: &Ir,
+) -> Result<Vec<(PathBuf, String)>, Error> {
+    let target = T::from_options(root_name, options)?;
+    let ast = target.transform(ir)?;
+    target.serialize(&ast)
+}
@@ -1,14 +1,15 @@
-mod target;
-mod targets;
-
-use clap::{App, AppSettings, Arg};
-
+use clap::{App, AppSettings, Arg, ArgMatches};
+use failure::format_err;
 use failure::Error;
+use inflector::Inflector;
 use jddf::{Schema, SerdeSchema};
-use std::fs::File;
-use target::Target;
+use jddf_codegen::targets::{csharp, golang, java, kotlin, python, rust, swift, typescript};
+use jddf_codegen::Options;
+use std::fs::{self, File};
+use std::path::PathBuf;
+
 fn main() -> Result<(), Error> {
-    let app = App::new("jddf-codegen")
+    let matches = App::new("jddf-codegen")
         .version("0.1")
         .about("Generates data structures from JDDF schemas")
         .setting(AppSettings::ColoredHelp)
@@ -17,15 +18,126 @@ fn main() -> Result<(), Error> {
                 .help("Input JDDF schema file")
                 .last(true)
                 .required(true),
-        );
-
-    let app = targets::typescript::Target::args(app);
-    let app = targets::golang::Target::args(app);
-
-    let matches = app.get_matches();
-
-    let target_ts = targets::typescript::Target::from_args(&matches)?;
-    let target_go = targets::golang::Target::from_args(&matches)?;
+        )
+        .arg(
+            Arg::with_name("root-name")
+                .help("Name of the type generated for the root schema, instead of one from INPUT")
+                .takes_value(true)
+                .long("root-name"),
+        )
+        .arg(
+            Arg::with_name("ts-out")
+                .help("Typescript output directory")
+                .takes_value(true)
+                .long("ts-out"),
+        )
+        .arg(
+            Arg::with_name("ts-eslint-disable")
+                .help("Turn ESLint off for the generated Typescript")
+                .long("ts-eslint-disable"),
+        )
+        .arg(
+            Arg::with_name("go-out")
+                .help("Golang output directory")
+                .takes_value(true)
+                .long("go-out"),
+        )
+        .arg(
+            Arg::with_name("go-package")
+                .help("Golang package name, instead of one from --go-out")
+                .takes_value(true)
+                .long("go-package"),
+        )
+        .arg(
+            Arg::with_name("go-file")
+                .help("Golang file name, instead of one from the root name")
+                .takes_value(true)
+                .long("go-file"),
+        )
+        .arg(
+            Arg::with_name("go-discriminator-style")
+                .help("How to represent discriminators in Golang")
+                .takes_value(true)
+                .possible_values(&["embedded", "interface"])
+                .default_value("embedded")
+                .long("go-discriminator-style"),
+        )
+        .arg(
+            Arg::with_name("go-timestamp")
+                .help("How to represent timestamps in Golang")
+                .takes_value(true)
+                .possible_values(&["time", "string", "wrapper"])
+                .default_value("time")
+                .long("go-timestamp"),
+        )
+        .arg(
+            Arg::with_name("go-preserve-additional")
+                .help("Keep unknown properties in an Extra field, where the schema allows them")
+                .long("go-preserve-additional"),
+        )
+        .arg(
+            Arg::with_name("go-split-files")
+                .help("Write each Golang definition to a file of its own")
+                .long("go-split-files"),
+        )
+        .arg(
+            Arg::with_name("rust-out")
+                .help("Rust output directory")
+                .takes_value(true)
+                .long("rust-out"),
+        )
+        .arg(
+            Arg::with_name("python-out")
+                .help("Python output directory")
+                .takes_value(true)
+                .long("python-out"),
+        )
+        .arg(
+            Arg::with_name("java-out")
+                .help("Java output directory")
+                .takes_value(true)
+                .long("java-out")
+                .requires("java-package"),
+        )
+        .arg(
+            Arg::with_name("java-package")
+                .help("Java package to generate classes into")
+                .takes_value(true)
+                .long("java-package"),
+        )
+        .arg(
+            Arg::with_name("kotlin-out")
+                .help("Kotlin output directory")
+                .takes_value(true)
+                .long("kotlin-out")
+                .requires("kotlin-package"),
+        )
+        .arg(
+            Arg::with_name("kotlin-package")
+                .help("Kotlin package to generate classes into")
+                .takes_value(true)
+                .long("kotlin-package"),
+        )
+        .arg(
+            Arg::with_name("swift-out")
+                .help("Swift output directory")
+                .takes_value(true)
+                .long("swift-out"),
+        )
+        .arg(
+            Arg::with_name("csharp-out")
+                .help("C# output directory")
+                .takes_value(true)
+                .long("csharp-out")
+                .requires("csharp-namespace"),
+        )
+        .arg(
+            Arg::with_name("csharp-namespace")
+                .help("C# namespace to generate types into")
+                .takes_value(true)
+                .long("csharp-namespace"),
+        )
+        .get_matches();
 
     // Parse out the input schema, and ensure it is valid.
     let input = matches.value_of("INPUT").unwrap();
@@ -33,29 +145,109 @@ fn main() -> Result<(), Error> {
     let serde_schema: SerdeSchema = serde_json::from_reader(file)?;
     let schema = Schema::from_serde(serde_schema)?;
 
-    // Run each of the target transformation routines. If any fail, do not
-    // generate code.
-    let ast_ts = if let Some(ref t) = target_ts {
-        Some(t.transform(&schema)?)
-    } else {
-        None
-    };
+    let options = options(&matches)?;
+
+    // Generate all of the code before writing any of it out, so that a failure
+    // in any one target does not leave partial output behind.
+    for (path, contents) in jddf_codegen::generate(&schema, &options)? {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, contents)?;
+    }
+
+    Ok(())
+}
 
-    let ast_go = if let Some(ref t) = target_go {
-        Some(t.transform(&schema)?)
-    } else {
-        None
+fn options(matches: &ArgMatches) -> Result<Options, Error> {
+    let input = matches.value_of("INPUT").unwrap();
+
+    let root_name = match matches.value_of("root-name") {
+        Some(root_name) => root_name.to_owned(),
+        None => {
+            // Infer a root name from the file name of the input schema.
+            let input_file_name = PathBuf::from(input)
+                .file_name()
+                .ok_or(format_err!("Could not infer file name from INPUT"))?
+                .to_str()
+                .ok_or(format_err!("Could not convert INPUT file name to UTF-8"))?
+                .to_owned();
+
+            input_file_name.split('.').next().unwrap().to_snake_case()
+        }
     };
 
-    // Serialize each of the ASTs. At this point, only IO errors can cause
-    // issues.
-    if let Some(ref t) = target_ts {
-        t.serialize(&ast_ts.unwrap())?;
+    let mut options = Options::new(&root_name);
+
+    if let Some(ts_out) = matches.value_of("ts-out") {
+        let mut typescript = typescript::Options::new(ts_out);
+        typescript.source = Some(PathBuf::from(input));
+        typescript.eslint_disable = matches.is_present("ts-eslint-disable");
+        options.typescript = Some(typescript);
     }
 
-    if let Some(ref t) = target_go {
-        t.serialize(&ast_go.unwrap())?;
+    if let Some(go_out) = matches.value_of("go-out") {
+        let package = match matches.value_of("go-package") {
+            Some(package) => package.to_owned(),
+            None => {
+                // Infer a package name from the output directory.
+                PathBuf::from(go_out)
+                    .components()
+                    .next_back()
+                    .ok_or(format_err!(
+                        "Could not determine package name from --go-out"
+                    ))?
+                    .as_os_str()
+                    .to_str()
+                    .ok_or(format_err!("Could not convert --go-out to UTF-8"))?
+                    .to_snake_case()
+            }
+        };
+
+        let mut golang = golang::Options::new(go_out, &package);
+        golang.file = matches.value_of("go-file").map(str::to_owned);
+        golang.preserve_additional = matches.is_present("go-preserve-additional");
+        golang.discriminator_style = match matches.value_of("go-discriminator-style") {
+            Some("interface") => golang::DiscriminatorStyle::Interface,
+            _ => golang::DiscriminatorStyle::Embedded,
+        };
+        golang.timestamp_style = match matches.value_of("go-timestamp") {
+            Some("string") => golang::TimestampStyle::String,
+            Some("wrapper") => golang::TimestampStyle::Wrapper,
+            _ => golang::TimestampStyle::Time,
+        };
+        golang.source = Some(PathBuf::from(input));
+        golang.split_files = matches.is_present("go-split-files");
+        options.golang = Some(golang);
     }
 
-    Ok(())
+    if let Some(rust_out) = matches.value_of("rust-out") {
+        options.rust = Some(rust::Options::new(rust_out));
+    }
+
+    if let Some(python_out) = matches.value_of("python-out") {
+        options.python = Some(python::Options::new(python_out));
+    }
+
+    if let Some(java_out) = matches.value_of("java-out") {
+        let package = matches.value_of("java-package").unwrap();
+        options.java = Some(java::Options::new(java_out, package));
+    }
+
+    if let Some(kotlin_out) = matches.value_of("kotlin-out") {
+        let package = matches.value_of("kotlin-package").unwrap();
+        options.kotlin = Some(kotlin::Options::new(kotlin_out, package));
+    }
+
+    if let Some(swift_out) = matches.value_of("swift-out") {
+        options.swift = Some(swift::Options::new(swift_out));
+    }
+
+    if let Some(csharp_out) = matches.value_of("csharp-out") {
+        let namespace = matches.value_of("csharp-namespace").unwrap();
+        options.csharp = Some(csharp::Options::new(csharp_out, namespace));
+    }
+
+    Ok(options)
 }
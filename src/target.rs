@@ -1,15 +1,34 @@
-use clap::{App, ArgMatches};
+use crate::ir::Ir;
 use failure::Error;
-use jddf::Schema;
+use std::path::{Path, PathBuf};
 
 pub trait Target
 where
     Self: Sized,
 {
+    type Options;
     type Ast;
 
-    fn args<'a, 'b>(app: App<'a, 'b>) -> App<'a, 'b>;
-    fn from_args(matches: &ArgMatches) -> Result<Option<Self>, Error>;
-    fn transform(&self, schema: &Schema) -> Result<Self::Ast, Error>;
-    fn serialize(&self, ast: &Self::Ast) -> Result<(), Error>;
+    fn from_options(root_name: &str, options: &Self::Options) -> Result<Self, Error>;
+    fn transform(&self, ir: &Ir) -> Result<Self::Ast, Error>;
+    fn serialize(&self, ast: &Self::Ast) -> Result<Vec<(PathBuf, String)>, Error>;
+}
+
+/// The lines of the comment at the top of a generated file, without comment
+/// markers.
+///
+/// The first line is in the form Go's tooling looks for to tell generated
+/// files apart from those written by hand.
+pub fn header(source: Option<&Path>) -> Vec<String> {
+    let mut lines = vec![
+        "Code generated by jddf-codegen. DO NOT EDIT.".to_owned(),
+        "".to_owned(),
+    ];
+
+    if let Some(source) = source {
+        lines.push(format!("Source: {}", source.display()));
+    }
+    lines.push(format!("Version: {}", env!("CARGO_PKG_VERSION")));
+
+    lines
 }
@@ -0,0 +1,550 @@
+use crate::ir::{DeclKind, Discriminator, Field, Ir, Struct, Type};
+use crate::target;
+use failure::Error;
+use inflector::Inflector;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Options for generating C#.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Options {
+    /// The directory the generated C# is written into.
+    pub out_dir: PathBuf,
+
+    /// The C# namespace to generate types into.
+    pub namespace: String,
+}
+
+impl Options {
+    /// Construct options for writing C# into `out_dir`, under the given
+    /// namespace.
+    pub fn new<P: Into<PathBuf>>(out_dir: P, namespace: &str) -> Options {
+        Options {
+            out_dir: out_dir.into(),
+            namespace: namespace.to_owned(),
+        }
+    }
+}
+
+pub struct Target {
+    out_path: PathBuf,
+    namespace: String,
+}
+
+impl target::Target for Target {
+    type Options = Options;
+    type Ast = Ast;
+
+    fn from_options(root_name: &str, options: &Options) -> Result<Self, Error> {
+        Ok(Target {
+            out_path: options
+                .out_dir
+                .join(format!("{}.cs", root_name.to_pascal_case())),
+            namespace: options.namespace.clone(),
+        })
+    }
+
+    fn transform(&self, ir: &Ir) -> Result<Ast, Error> {
+        let mut seq = vec![];
+
+        for decl in &ir.decls {
+            match &decl.kind {
+                DeclKind::Struct(Struct { fields, .. }) => {
+                    seq.push(Ast::Record(Record {
+                        name: decl.name.clone(),
+                        base: None,
+                        properties: self.transform_fields(&decl.name, fields),
+                    }));
+                }
+                DeclKind::Enum(values) => {
+                    let members = values
+                        .iter()
+                        .map(|val| EnumMember {
+                            name: member_name(&val.json),
+                            json: val.json.to_owned(),
+                        })
+                        .collect();
+
+                    seq.push(Ast::Enum(decl.name.clone(), members));
+                }
+                DeclKind::Discriminator(Discriminator { tag, variants }) => {
+                    for variant in variants {
+                        seq.push(Ast::Record(Record {
+                            name: variant.name.clone(),
+                            base: Some(decl.name.clone()),
+                            properties: self.transform_fields(&variant.name, &variant.body.fields),
+                        }));
+                    }
+
+                    seq.push(Ast::Discriminator {
+                        name: decl.name.clone(),
+                        tag_json: tag.to_owned(),
+                        variants: variants
+                            .iter()
+                            .map(|variant| (variant.tag_value.to_owned(), variant.name.clone()))
+                            .collect(),
+                    });
+                }
+                // An alias which holds itself could never be expanded in full,
+                // so these are given a record of their own.
+                DeclKind::Alias(ty) if ir.is_recursive_alias(&decl.name) => {
+                    seq.push(Ast::Wrapper(
+                        decl.name.clone(),
+                        Box::new(self.transform_type(ty)),
+                    ));
+                }
+                DeclKind::Alias(ty) => {
+                    seq.push(Ast::Typedef(
+                        decl.name.clone(),
+                        Box::new(self.transform_type(ty)),
+                    ));
+                }
+            }
+        }
+
+        Ok(Ast::Sequence(seq))
+    }
+
+    fn serialize(&self, ast: &Ast) -> Result<Vec<(PathBuf, String)>, Error> {
+        let mut out = Vec::new();
+
+        writeln!(out, "#nullable enable")?;
+        writeln!(out)?;
+        writeln!(out, "using System;")?;
+        writeln!(out, "using System.Collections.Generic;")?;
+        writeln!(out, "using System.Text.Json;")?;
+        writeln!(out, "using System.Text.Json.Serialization;")?;
+        writeln!(out)?;
+        writeln!(out, "namespace {};", self.namespace)?;
+        writeln!(out)?;
+
+        // C# has no type aliases which can be shared between files, so a
+        // reference to a definition which isn't a record or enum is replaced by
+        // the definition's type itself.
+        let mut aliases = HashMap::new();
+        if let Ast::Sequence(asts) = ast {
+            for ast in asts {
+                if let Ast::Typedef(name, ast) = ast {
+                    aliases.insert(name.clone(), ast.as_ref());
+                }
+            }
+        }
+
+        self.serialize_ast(&mut out, &aliases, ast)?;
+
+        Ok(vec![(self.out_path.clone(), String::from_utf8(out)?)])
+    }
+}
+
+impl Target {
+    fn transform_type(&self, ty: &Type) -> Ast {
+        match ty {
+            Type::Any => Ast::JsonElement,
+            Type::Boolean => Ast::Bool,
+            Type::String => Ast::String,
+            Type::Timestamp => Ast::DateTimeOffset,
+            Type::Int8 => Ast::Sbyte,
+            Type::Uint8 => Ast::Byte,
+            Type::Int16 => Ast::Short,
+            Type::Uint16 => Ast::Ushort,
+            Type::Int32 => Ast::Int,
+            Type::Uint32 => Ast::Uint,
+            Type::Float32 => Ast::Float,
+            Type::Float64 => Ast::Double,
+            Type::Elements(ty) => Ast::List(Box::new(self.transform_type(ty))),
+            Type::Values(ty) => Ast::Dictionary(Box::new(self.transform_type(ty))),
+            Type::Ref(name) => Ast::Identifier(name.clone()),
+            Type::Nullable(ty) => Ast::Nullable(Box::new(self.transform_type(ty))),
+            Type::Custom(ty, _) => self.transform_type(ty),
+        }
+    }
+
+    fn transform_fields(&self, record: &str, fields: &[Field]) -> Vec<Property> {
+        fields
+            .iter()
+            .map(|field| Property {
+                name: property_name(record, &field.json),
+                required: field.required,
+                json: field.json.to_owned(),
+                ast: self.transform_type(&field.ty),
+            })
+            .collect()
+    }
+
+    fn serialize_ast(&self, w: &mut dyn Write, aliases: &Aliases, ast: &Ast) -> Result<(), Error> {
+        match ast {
+            Ast::JsonElement => write!(w, "JsonElement")?,
+            Ast::Bool => write!(w, "bool")?,
+            Ast::Sbyte => write!(w, "sbyte")?,
+            Ast::Byte => write!(w, "byte")?,
+            Ast::Short => write!(w, "short")?,
+            Ast::Ushort => write!(w, "ushort")?,
+            Ast::Int => write!(w, "int")?,
+            Ast::Uint => write!(w, "uint")?,
+            Ast::Float => write!(w, "float")?,
+            Ast::Double => write!(w, "double")?,
+            Ast::String => write!(w, "string")?,
+            Ast::DateTimeOffset => write!(w, "DateTimeOffset")?,
+            Ast::List(ast) => {
+                write!(w, "List<")?;
+                self.serialize_ast(w, aliases, ast)?;
+                write!(w, ">")?;
+            }
+            Ast::Dictionary(ast) => {
+                write!(w, "Dictionary<string, ")?;
+                self.serialize_ast(w, aliases, ast)?;
+                write!(w, ">")?;
+            }
+            Ast::Nullable(ast) => {
+                self.serialize_ast(w, aliases, ast)?;
+                write!(w, "?")?;
+            }
+            Ast::Record(Record {
+                name,
+                base,
+                properties,
+            }) => {
+                match base {
+                    Some(base) => writeln!(w, "public record {} : {}", name, base)?,
+                    None => writeln!(w, "public record {}", name)?,
+                }
+                writeln!(w, "{{")?;
+                for (i, prop) in properties.iter().enumerate() {
+                    if i != 0 {
+                        writeln!(w)?;
+                    }
+
+                    writeln!(w, "    [JsonPropertyName({})]", quote(&prop.json))?;
+                    if prop.required {
+                        write!(w, "    public ")?;
+                        self.serialize_ast(w, aliases, &prop.ast)?;
+                        writeln!(w, " {} {{ get; init; }} = default!;", prop.name)?;
+                    } else {
+                        writeln!(
+                            w,
+                            "    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]"
+                        )?;
+                        write!(w, "    public ")?;
+                        self.serialize_ast(w, aliases, &prop.ast)?;
+                        if let Ast::Nullable(_) = prop.ast {
+                            writeln!(w, " {} {{ get; init; }}", prop.name)?;
+                        } else {
+                            writeln!(w, "? {} {{ get; init; }}", prop.name)?;
+                        }
+                    }
+                }
+                writeln!(w, "}}")?;
+            }
+            Ast::Enum(name, members) => {
+                writeln!(w, "[JsonConverter(typeof({}Converter))]", name)?;
+                writeln!(w, "public enum {}", name)?;
+                writeln!(w, "{{")?;
+                for member in members {
+                    writeln!(w, "    {},", member.name)?;
+                }
+                writeln!(w, "}}")?;
+                writeln!(w)?;
+
+                writeln!(
+                    w,
+                    "public class {}Converter : JsonConverter<{}>",
+                    name, name
+                )?;
+                writeln!(w, "{{")?;
+                writeln!(
+                    w,
+                    "    public override {} Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)",
+                    name
+                )?;
+                writeln!(w, "    {{")?;
+                writeln!(w, "        return reader.GetString() switch")?;
+                writeln!(w, "        {{")?;
+                for member in members {
+                    writeln!(
+                        w,
+                        "            {} => {}.{},",
+                        quote(&member.json),
+                        name,
+                        member.name
+                    )?;
+                }
+                writeln!(
+                    w,
+                    "            var value => throw new JsonException($\"unknown {} value: {{value}}\"),",
+                    name
+                )?;
+                writeln!(w, "        }};")?;
+                writeln!(w, "    }}")?;
+                writeln!(w)?;
+                writeln!(
+                    w,
+                    "    public override void Write(Utf8JsonWriter writer, {} value, JsonSerializerOptions options)",
+                    name
+                )?;
+                writeln!(w, "    {{")?;
+                writeln!(w, "        writer.WriteStringValue(value switch")?;
+                writeln!(w, "        {{")?;
+                for member in members {
+                    writeln!(
+                        w,
+                        "            {}.{} => {},",
+                        name,
+                        member.name,
+                        quote(&member.json)
+                    )?;
+                }
+                writeln!(
+                    w,
+                    "            _ => throw new JsonException($\"unknown {} value: {{value}}\"),",
+                    name
+                )?;
+                writeln!(w, "        }});")?;
+                writeln!(w, "    }}")?;
+                writeln!(w, "}}")?;
+            }
+            Ast::Discriminator {
+                name,
+                tag_json,
+                variants,
+            } => {
+                writeln!(w, "[JsonConverter(typeof({}Converter))]", name)?;
+                writeln!(w, "public abstract record {};", name)?;
+                writeln!(w)?;
+
+                writeln!(
+                    w,
+                    "public class {}Converter : JsonConverter<{}>",
+                    name, name
+                )?;
+                writeln!(w, "{{")?;
+                writeln!(
+                    w,
+                    "    public override {} Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)",
+                    name
+                )?;
+                writeln!(w, "    {{")?;
+                writeln!(
+                    w,
+                    "        using var document = JsonDocument.ParseValue(ref reader);"
+                )?;
+                writeln!(w, "        var root = document.RootElement;")?;
+                writeln!(
+                    w,
+                    "        if (!root.TryGetProperty({}, out var tag) || tag.ValueKind != JsonValueKind.String)",
+                    quote(tag_json)
+                )?;
+                writeln!(w, "        {{")?;
+                writeln!(
+                    w,
+                    "            throw new JsonException(\"missing discriminator tag\");"
+                )?;
+                writeln!(w, "        }}")?;
+                writeln!(w)?;
+                writeln!(w, "        return tag.GetString() switch")?;
+                writeln!(w, "        {{")?;
+                for (tag_value, variant) in variants {
+                    writeln!(
+                        w,
+                        "            {} => root.Deserialize<{}>(options)!,",
+                        quote(tag_value),
+                        variant
+                    )?;
+                }
+                writeln!(
+                    w,
+                    "            var value => throw new JsonException($\"unknown discriminator tag value: {{value}}\"),"
+                )?;
+                writeln!(w, "        }};")?;
+                writeln!(w, "    }}")?;
+                writeln!(w)?;
+                writeln!(
+                    w,
+                    "    public override void Write(Utf8JsonWriter writer, {} value, JsonSerializerOptions options)",
+                    name
+                )?;
+                writeln!(w, "    {{")?;
+                writeln!(w, "        var (tag, element) = value switch")?;
+                writeln!(w, "        {{")?;
+                for (tag_value, variant) in variants {
+                    writeln!(
+                        w,
+                        "            {} v => ({}, JsonSerializer.SerializeToElement(v, options)),",
+                        variant,
+                        quote(tag_value)
+                    )?;
+                }
+                writeln!(
+                    w,
+                    "            _ => throw new JsonException(\"unknown discriminator variant\"),"
+                )?;
+                writeln!(w, "        }};")?;
+                writeln!(w)?;
+                writeln!(w, "        writer.WriteStartObject();")?;
+                writeln!(w, "        writer.WriteString({}, tag);", quote(tag_json))?;
+                writeln!(
+                    w,
+                    "        foreach (var property in element.EnumerateObject())"
+                )?;
+                writeln!(w, "        {{")?;
+                writeln!(w, "            property.WriteTo(writer);")?;
+                writeln!(w, "        }}")?;
+                writeln!(w, "        writer.WriteEndObject();")?;
+                writeln!(w, "    }}")?;
+                writeln!(w, "}}")?;
+            }
+            Ast::Wrapper(name, ast) => {
+                let mut ty = Vec::new();
+                self.serialize_ast(&mut ty, aliases, ast)?;
+                let ty = String::from_utf8(ty)?;
+
+                writeln!(w, "[JsonConverter(typeof({}Converter))]", name)?;
+                writeln!(w, "public record {}({} Value);", name, ty)?;
+                writeln!(w)?;
+
+                writeln!(
+                    w,
+                    "public class {}Converter : JsonConverter<{}>",
+                    name, name
+                )?;
+                writeln!(w, "{{")?;
+                writeln!(
+                    w,
+                    "    public override {} Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)",
+                    name
+                )?;
+                writeln!(w, "    {{")?;
+                writeln!(
+                    w,
+                    "        return new {}(JsonSerializer.Deserialize<{}>(ref reader, options)!);",
+                    name, ty
+                )?;
+                writeln!(w, "    }}")?;
+                writeln!(w)?;
+                writeln!(
+                    w,
+                    "    public override void Write(Utf8JsonWriter writer, {} value, JsonSerializerOptions options)",
+                    name
+                )?;
+                writeln!(w, "    {{")?;
+                writeln!(
+                    w,
+                    "        JsonSerializer.Serialize(writer, value.Value, options);"
+                )?;
+                writeln!(w, "    }}")?;
+                writeln!(w, "}}")?;
+            }
+            Ast::Identifier(id) => match aliases.get(id) {
+                Some(ast) => self.serialize_ast(w, aliases, ast)?,
+                None => write!(w, "{}", id)?,
+            },
+            // Aliases are never written out themselves; see `serialize`.
+            Ast::Typedef(_, _) => {}
+            Ast::Sequence(asts) => {
+                for ast in asts {
+                    if let Ast::Typedef(_, _) = ast {
+                        continue;
+                    }
+
+                    self.serialize_ast(w, aliases, ast)?;
+                    writeln!(w)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// The aliases which are expanded wherever they are referred to. None of these
+// refer back to themselves, as those are wrapped instead.
+type Aliases<'a> = HashMap<String, &'a Ast>;
+
+fn property_name(record: &str, json: &str) -> String {
+    let name = member_name(json);
+
+    // A member cannot have the same name as the type it belongs to.
+    if name == record {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+fn member_name(json: &str) -> String {
+    // C# keywords are all lower-case, so they can never collide with a
+    // PascalCase name.
+    let name = json.to_pascal_case();
+    if name.starts_with(char::is_alphabetic) {
+        name
+    } else {
+        format!("X{}", name)
+    }
+}
+
+/// A C# string literal with the given contents.
+fn quote(s: &str) -> String {
+    // JSON string escapes are a subset of C#'s.
+    serde_json::to_string(s).unwrap()
+}
+
+#[derive(Debug)]
+pub enum Ast {
+    JsonElement,
+    Bool,
+    Sbyte,
+    Byte,
+    Short,
+    Ushort,
+    Int,
+    Uint,
+    Float,
+    Double,
+    String,
+    DateTimeOffset,
+    List(Box<Ast>),
+    Dictionary(Box<Ast>),
+    Nullable(Box<Ast>),
+    Record(Record),
+    Enum(String, Vec<EnumMember>),
+    Discriminator {
+        // the name of the abstract base record
+        name: String,
+        // the name of the tag as it appears in JSON
+        tag_json: String,
+        // the tag values, and the name of the record each one maps to
+        variants: Vec<(String, String)>,
+    },
+    Identifier(String),
+    Typedef(String, Box<Ast>),
+    // a record holding a single value, which is read and written as that value
+    Wrapper(String, Box<Ast>),
+    Sequence(Vec<Ast>),
+}
+
+#[derive(Debug)]
+pub struct Record {
+    // the name of the record
+    name: String,
+    // the discriminator base record, if this is a discriminator variant
+    base: Option<String>,
+    // the properties of the record
+    properties: Vec<Property>,
+}
+
+#[derive(Debug)]
+pub struct EnumMember {
+    // the member's name as it appears in C#
+    name: String,
+    // the member's value as it appears in JSON
+    json: String,
+}
+
+#[derive(Debug)]
+pub struct Property {
+    name: String,
+    required: bool,
+    json: String,
+    ast: Ast,
+}
@@ -1,395 +1,2313 @@
+use crate::ir::{self, DeclKind, Discriminator, Field, Ir, Struct, Type};
 use crate::target;
-use clap::{App, Arg, ArgMatches};
-use failure::format_err;
-use failure::Error;
+use failure::{format_err, Error};
 use inflector::Inflector;
-use jddf::{Form, Schema, Type};
-use std::fs::File;
-use std::io::{BufWriter, Write};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::PathBuf;
 
+/// Options for generating Go.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Options {
+    /// The directory the generated Go is written into.
+    pub out_dir: PathBuf,
+
+    /// The name of the Go package the generated code belongs to.
+    pub package: String,
+
+    /// The name of the file the generated Go is written to. If not set, the
+    /// file is named after the root type.
+    pub file: Option<String>,
+
+    /// Whether structs which allow additional properties keep them in an
+    /// `Extra` field, rather than discarding them.
+    pub preserve_additional: bool,
+
+    /// How discriminators are represented.
+    pub discriminator_style: DiscriminatorStyle,
+
+    /// How timestamps are represented.
+    pub timestamp_style: TimestampStyle,
+
+    /// The path of the schema, which is recorded in the generated code.
+    pub source: Option<PathBuf>,
+
+    /// Whether each definition is written to a file of its own, rather than
+    /// everything going in a single file. The root type goes in the file
+    /// named by `file`, and what is shared between them in `jddf_common.go`.
+    pub split_files: bool,
+}
+
+impl Options {
+    /// Construct options for writing Go into `out_dir`, as part of the given
+    /// package. Everything else is left at its default: a single file named
+    /// after the root type, with additional properties discarded.
+    pub fn new<P: Into<PathBuf>>(out_dir: P, package: &str) -> Options {
+        Options {
+            out_dir: out_dir.into(),
+            package: package.to_owned(),
+            file: None,
+            preserve_additional: false,
+            discriminator_style: DiscriminatorStyle::default(),
+            timestamp_style: TimestampStyle::default(),
+            source: None,
+            split_files: false,
+        }
+    }
+}
+
+/// The ways a timestamp can be represented in Go.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TimestampStyle {
+    /// A `time.Time`, which is encoded in RFC 3339 format regardless of how the
+    /// timestamp was written when decoded.
+    #[default]
+    Time,
+
+    /// A `string`, holding the timestamp as it was written. It is not checked
+    /// to be in RFC 3339 format.
+    String,
+
+    /// A generated `Timestamp` struct, holding both the parsed `time.Time` and
+    /// the text it was parsed from. The text is kept when encoding, unless the
+    /// time has since been changed.
+    Wrapper,
+}
+
+// The file holding what the others share, when writing one file per
+// definition.
+const COMMON_FILE: &str = "jddf_common.go";
+
+// The name of the type generated for TimestampStyle::Wrapper.
+const TIMESTAMP: &str = "Timestamp";
+
+// The first version of Go whose encoding/json supports omitzero, which the
+// fields of optional, nullable properties rely on.
+const NULLABLE_GO_VERSION: &str = "1.24";
+
+/// The ways a discriminator can be represented in Go.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DiscriminatorStyle {
+    /// A struct holding the tag, with every variant's struct embedded in it.
+    #[default]
+    Embedded,
+
+    /// A struct holding a single variant, by way of an interface which each
+    /// variant's struct implements.
+    Interface,
+}
+
 pub struct Target {
-    out_path: PathBuf,
-    root_name: String,
+    out_dir: PathBuf,
+    file: String,
+    split_files: bool,
     pkg_name: String,
+    preserve_additional: bool,
+    discriminator_style: DiscriminatorStyle,
+    timestamp_style: TimestampStyle,
+    source: Option<PathBuf>,
 }
 
 impl target::Target for Target {
+    type Options = Options;
     type Ast = Ast;
 
-    fn args<'a, 'b>(app: App<'a, 'b>) -> App<'a, 'b> {
-        app.arg(
-            Arg::with_name("go-out")
-                .help("Golang output directory")
-                .takes_value(true)
-                .long("go-out"),
-        )
-    }
-
-    fn from_args(matches: &ArgMatches) -> Result<Option<Self>, Error> {
-        if let Some(go_out) = matches.value_of("go-out") {
-            // Infer a package name from the output directory.
-            let pkg_name = PathBuf::from(go_out)
-                .components()
-                .last()
-                .ok_or(format_err!(
-                    "Could not determine package name from --go-out"
-                ))?
-                .as_os_str()
-                .to_str()
-                .ok_or(format_err!("Could not convert --go-out to UTF-8"))?
-                .to_snake_case();
-
-            // Infer a root name from the file name of the input schema.
-            let input = PathBuf::from(matches.value_of("INPUT").unwrap());
-
-            let input_file_name = input
-                .file_name()
-                .ok_or(format_err!("Could not infer file name from INPUT"))?;
-
-            let input_file_name = input_file_name
-                .to_str()
-                .ok_or(format_err!("Could not convert INPUT file name to UTF-8"))?;
-
-            let root_name = input_file_name.split(".").next().unwrap().to_snake_case();
-
-            let out_path = PathBuf::from(go_out).join(format!("{}.go", root_name));
-
-            Ok(Some(Target {
-                out_path,
-                root_name,
-                pkg_name,
-            }))
-        } else {
-            Ok(None)
+    fn from_options(root_name: &str, options: &Options) -> Result<Self, Error> {
+        // The blank identifier is a valid identifier, but not a valid package
+        // name.
+        if !is_identifier(&options.package) || options.package == "_" {
+            return Err(format_err!(
+                "{:?} is not a valid Go package name",
+                options.package
+            ));
+        }
+
+        let file = match &options.file {
+            Some(file) => {
+                check_file_name(file)?;
+                file.clone()
+            }
+            None => format!("{}.go", root_name.to_snake_case()),
+        };
+
+        if options.split_files && file == COMMON_FILE {
+            return Err(format_err!(
+                "{:?} is where the shared declarations go, and so cannot hold the root type",
+                file
+            ));
         }
+
+        Ok(Target {
+            out_dir: options.out_dir.clone(),
+            file,
+            split_files: options.split_files,
+            pkg_name: options.package.clone(),
+            preserve_additional: options.preserve_additional,
+            discriminator_style: options.discriminator_style,
+            timestamp_style: options.timestamp_style,
+            source: options.source.clone(),
+        })
     }
 
-    fn transform(&self, schema: &Schema) -> Result<Ast, Error> {
+    fn transform(&self, ir: &Ir) -> Result<Ast, Error> {
         let mut seq = vec![];
+        // the file each element of seq goes in, when splitting them up
+        let mut files = vec![];
 
-        if let Some(defs) = schema.definitions() {
-            for (name, schema) in defs {
-                let ast = self.transform_subschema(&mut seq, &mut vec![name], schema)?;
-                self.ensure_has_name(Some(name), &mut seq, ast);
+        for decl in &ir.decls {
+            match &decl.kind {
+                DeclKind::Struct(body) => {
+                    seq.push(Ast::Struct(self.transform_struct(
+                        &decl.name,
+                        &decl.description,
+                        &decl.schema_path,
+                        None,
+                        body,
+                    )?));
+                }
+                DeclKind::Enum(values) => {
+                    let members = values
+                        .iter()
+                        .map(|val| EnumMember {
+                            name: ir::member_name(&decl.name, &val.json),
+                            json: val.json.to_owned(),
+                            description: val.description.clone(),
+                        })
+                        .collect();
+
+                    seq.push(self.documented(
+                        &decl.description,
+                        Ast::Enum(decl.name.clone(), decl.schema_path.clone(), members),
+                    ));
+                }
+                DeclKind::Discriminator(Discriminator { tag, variants })
+                    if self.discriminator_style == DiscriminatorStyle::Interface =>
+                {
+                    let interface = format!("{}Variant", decl.name);
+                    if ir.decls.iter().any(|decl| decl.name == interface)
+                        || variants.iter().any(|variant| variant.name == interface)
+                    {
+                        return Err(format_err!(
+                            "The interface for {} would be named {}, which is already taken",
+                            decl.name,
+                            interface
+                        ));
+                    }
+
+                    let mut discriminator_variants = Vec::new();
+                    for variant in variants {
+                        discriminator_variants.push(self.transform_struct(
+                            &variant.name,
+                            &variant.description,
+                            &variant.schema_path,
+                            Some((tag, &variant.tag_value)),
+                            &variant.body,
+                        )?);
+                    }
+
+                    seq.push(self.documented(
+                        &decl.description,
+                        Ast::DiscriminatorInterface {
+                            name: decl.name.clone(),
+                            interface,
+                            tag_json: tag.to_owned(),
+                            schema_path: decl.schema_path.clone(),
+                            variants: discriminator_variants,
+                        },
+                    ));
+                }
+                DeclKind::Discriminator(Discriminator { tag, variants }) => {
+                    // Create the enum for the values the tag can take on.
+                    let tag_enum_name = ir::member_name(&decl.name, tag);
+                    seq.push(Ast::Typedef(
+                        tag_enum_name.clone(),
+                        decl.schema_path.clone(),
+                        Box::new(Ast::String),
+                    ));
+
+                    // Loop over the mapping values. For each one, we must
+                    // generate both a value for the tag enum, and a variant
+                    // struct.
+                    let mut discriminator_variants = Vec::new();
+                    for variant in variants {
+                        seq.push(Ast::Const(
+                            ir::member_name(&tag_enum_name, &variant.tag_value),
+                            tag_enum_name.clone(),
+                            Box::new(Ast::StrConstant(variant.tag_value.to_owned())),
+                        ));
+
+                        discriminator_variants.push(self.transform_struct(
+                            &variant.name,
+                            &variant.description,
+                            &variant.schema_path,
+                            Some((tag, &variant.tag_value)),
+                            &variant.body,
+                        )?);
+                    }
+
+                    seq.push(self.documented(
+                        &decl.description,
+                        Ast::DiscriminatorStruct {
+                            name: decl.name.clone(),
+                            tag: tag_enum_name,
+                            tag_short: field_name(tag),
+                            tag_json: tag.to_owned(),
+                            schema_path: decl.schema_path.clone(),
+                            variants: discriminator_variants,
+                        },
+                    ));
+                }
+                // An alias cannot refer back to itself, but a defined type
+                // can.
+                DeclKind::Alias(ty) if ir.is_recursive_alias(&decl.name) => {
+                    seq.push(self.documented(
+                        &decl.description,
+                        Ast::Defined(decl.name.clone(), Box::new(self.transform_type(ty))),
+                    ));
+                }
+                DeclKind::Alias(ty) => {
+                    seq.push(self.documented(
+                        &decl.description,
+                        Ast::Typedef(
+                            decl.name.clone(),
+                            decl.schema_path.clone(),
+                            Box::new(self.transform_type(ty)),
+                        ),
+                    ));
+                }
+            }
+
+            if self.split_files {
+                files.resize(seq.len(), self.file_for(&decl.schema_path)?);
             }
         }
 
-        let ast = self.transform_subschema(&mut seq, &mut vec![&self.root_name], schema)?;
-        self.ensure_has_name(None, &mut seq, ast);
+        if !self.split_files {
+            return Ok(Ast::Sequence(seq));
+        }
+
+        // Files are ordered by where their first declaration appears.
+        let mut split: Vec<(String, Vec<Ast>)> = Vec::new();
+        for (file, ast) in files.into_iter().zip(seq) {
+            match split.iter_mut().find(|(f, _)| *f == file) {
+                Some((_, seq)) => seq.push(ast),
+                None => split.push((file, vec![ast])),
+            }
+        }
 
-        Ok(Ast::Sequence(seq))
+        Ok(Ast::Files(
+            split
+                .into_iter()
+                .map(|(file, seq)| (file, Ast::Sequence(seq)))
+                .collect(),
+        ))
     }
 
-    fn serialize(&self, ast: &Ast) -> Result<(), Error> {
-        let mut out = BufWriter::new(File::create(self.out_path.clone())?);
+    fn serialize(&self, ast: &Ast) -> Result<Vec<(PathBuf, String)>, Error> {
+        let decls = Decls::new(ast);
 
-        writeln!(out, "package {}", self.pkg_name)?;
-        writeln!(out, "import \"time\"")?;
-        writeln!(out, "import \"encoding/json\"")?;
-        writeln!(out, "import \"errors\"")?;
-        writeln!(
-            out,
-            "var ErrUnknownVariant = errors.New(\"{}: unknown discriminator tag value\")",
-            self.pkg_name
-        )?;
-        self.serialize_ast(&mut out, ast)?;
+        // The names of the schema's types were checked against each other
+        // when they were given out, but not against those of the declarations
+        // the generated Go makes for itself.
+        let mut names = self.helper_names(&decls, ast);
+        self.declared_names(&mut names, ast);
+        let mut seen = HashSet::new();
+        if let Some(name) = names.into_iter().find(|name| !seen.insert(name.clone())) {
+            return Err(format_err!(
+                "The generated Go needs a declaration named {}, which is already taken",
+                name
+            ));
+        }
+
+        let files = match ast {
+            Ast::Files(files) => files,
+            _ => {
+                let mut imports = self.helper_imports(&decls, ast);
+                self.decl_imports(&mut imports, &decls, ast);
 
-        Ok(())
+                let mut w = self.start_file(&imports, ast);
+                self.serialize_helpers(&mut w, &decls, ast);
+                self.serialize_decl(&mut w, &decls, ast);
+
+                return Ok(vec![(self.out_dir.join(&self.file), w.finish())]);
+            }
+        };
+
+        let mut w = self.start_file(&self.helper_imports(&decls, ast), ast);
+        self.serialize_helpers(&mut w, &decls, ast);
+        let mut out = vec![(self.out_dir.join(COMMON_FILE), w.finish())];
+
+        let root = ast;
+        for (file, ast) in files {
+            let mut imports = BTreeSet::new();
+            self.decl_imports(&mut imports, &decls, ast);
+
+            let mut w = self.start_file(&imports, root);
+            self.serialize_decl(&mut w, &decls, ast);
+            out.push((self.out_dir.join(file), w.finish()));
+        }
+
+        Ok(out)
     }
 }
 
 impl Target {
-    fn transform_subschema<'a>(
+    // Works out which file a declaration goes in, from the part of the schema
+    // it comes from: a definition, or failing that the root.
+    fn file_for(&self, schema_path: &str) -> Result<String, Error> {
+        let token = match schema_path.strip_prefix("/definitions/") {
+            Some(rest) => rest.split('/').next().unwrap(),
+            None => return Ok(self.file.clone()),
+        };
+
+        let definition = token.replace("~1", "/").replace("~0", "~");
+        let file = format!("{}.go", definition.to_snake_case());
+        if check_file_name(&file).is_err() || file == self.file || file == COMMON_FILE {
+            return Err(format_err!(
+                "The file for definition {:?} would be named {:?}, which cannot be used",
+                definition,
+                file
+            ));
+        }
+
+        Ok(file)
+    }
+
+    fn transform_type(&self, ty: &Type) -> Ast {
+        match ty {
+            Type::Any => Ast::EmptyInterface,
+            Type::Boolean => Ast::Boolean,
+            Type::String => Ast::String,
+            Type::Timestamp => match self.timestamp_style {
+                TimestampStyle::Time => Ast::Time,
+                TimestampStyle::String => Ast::String,
+                TimestampStyle::Wrapper => Ast::Timestamp,
+            },
+            Type::Int8 => Ast::Int8,
+            Type::Uint8 => Ast::Uint8,
+            Type::Int16 => Ast::Int16,
+            Type::Uint16 => Ast::Uint16,
+            Type::Int32 => Ast::Int32,
+            Type::Uint32 => Ast::Uint32,
+            Type::Float32 => Ast::Float32,
+            Type::Float64 => Ast::Float64,
+            Type::Elements(ty) => Ast::Array(Box::new(self.transform_type(ty))),
+            Type::Values(ty) => Ast::Map(Box::new(self.transform_type(ty))),
+            Type::Ref(name) => Ast::Identifier(name.clone()),
+            Type::Nullable(ty) => Ast::Pointer(Box::new(self.transform_type(ty))),
+            Type::Custom(ty, custom) => match &custom.go_type {
+                Some(go_type) => Ast::Custom(go_type.clone(), custom.go_import.clone()),
+                None => self.transform_type(ty),
+            },
+        }
+    }
+
+    fn transform_struct(
         &self,
-        seq: &mut Vec<Ast>,
-        name: &mut Vec<&'a str>,
-        schema: &'a Schema,
-    ) -> Result<Ast, Error> {
-        match schema.form() {
-            Form::Empty => Ok(Ast::EmptyInterface),
-            Form::Ref(def) => Ok(Ast::Identifier(self.name(&[&def]))),
-            Form::Type(Type::Boolean) => Ok(Ast::Boolean),
-            Form::Type(Type::String) => Ok(Ast::String),
-            Form::Type(Type::Timestamp) => Ok(Ast::Time),
-            Form::Type(Type::Int8) => Ok(Ast::Int8),
-            Form::Type(Type::Int16) => Ok(Ast::Int16),
-            Form::Type(Type::Int32) => Ok(Ast::Int32),
-            Form::Type(Type::Uint8) => Ok(Ast::Uint8),
-            Form::Type(Type::Uint16) => Ok(Ast::Uint16),
-            Form::Type(Type::Uint32) => Ok(Ast::Uint32),
-            Form::Type(Type::Float32) => Ok(Ast::Float32),
-            Form::Type(Type::Float64) => Ok(Ast::Float64),
-            Form::Enum(vals) => {
-                let enum_name = self.name(name);
-                seq.push(Ast::Typedef(enum_name.clone(), Box::new(Ast::String)));
-
-                for val in vals {
-                    name.push(val);
-                    seq.push(Ast::Const(
-                        self.name(name),
-                        enum_name.clone(),
-                        Box::new(Ast::StrConstant(val.to_owned())),
-                    ));
-                    name.pop();
+        name: &str,
+        description: &Option<String>,
+        schema_path: &str,
+        tag: Option<(&str, &str)>,
+        body: &Struct,
+    ) -> Result<StructAst, Error> {
+        let properties = self.transform_fields(&body.fields);
+
+        if let Some(prop) = properties.iter().find(|prop| !is_tag_name(&prop.json)) {
+            return Err(format_err!(
+                "{} has a property named {:?}, which encoding/json cannot take as a struct tag's name",
+                name,
+                prop.json
+            ));
+        }
+
+        if properties.iter().any(|prop| prop.name == "Validate") {
+            return Err(format_err!(
+                "{} has a property named Validate, which would collide with its Validate method",
+                name
+            ));
+        }
+
+        let additional = if !body.allow_additional {
+            Additional::Reject
+        } else if self.preserve_additional {
+            if properties.iter().any(|prop| prop.name == "Extra") {
+                return Err(format_err!(
+                    "{} has a property named Extra, which would collide with the field for additional properties",
+                    name
+                ));
+            }
+
+            Additional::Capture
+        } else {
+            Additional::Ignore
+        };
+
+        Ok(StructAst {
+            name: name.to_owned(),
+            description: description.clone(),
+            schema_path: schema_path.to_owned(),
+            tag: tag.map(|(json, value)| (json.to_owned(), value.to_owned())),
+            properties,
+            additional,
+        })
+    }
+
+    fn transform_fields(&self, fields: &[Field]) -> Vec<Property> {
+        fields
+            .iter()
+            .map(|field| {
+                let ast = self.transform_type(&field.ty);
+                let ast = match ast {
+                    _ if field.required => ast,
+                    // A nil pointer can stand for an absent property or a null
+                    // one, but not both at once.
+                    Ast::Pointer(ast) => Ast::Nullable(ast),
+                    _ => Ast::Pointer(Box::new(ast)),
+                };
+
+                Property {
+                    name: field_name(&field.json),
+                    json: field.json.to_owned(),
+                    required: field.required,
+                    description: field.description.clone(),
+                    ast,
                 }
+            })
+            .collect()
+    }
+
+    fn documented(&self, description: &Option<String>, ast: Ast) -> Ast {
+        match description {
+            Some(description) => Ast::Documented(description.clone(), Box::new(ast)),
+            None => ast,
+        }
+    }
+
+    fn uses_enum(&self, ast: &Ast) -> bool {
+        match ast {
+            Ast::Enum(..) => true,
+            Ast::Documented(_, ast) => self.uses_enum(ast),
+            Ast::Sequence(asts) => asts.iter().any(|ast| self.uses_enum(ast)),
+            Ast::Files(files) => files.iter().any(|(_, ast)| self.uses_enum(ast)),
+            _ => false,
+        }
+    }
+
+    fn uses_discriminator(&self, ast: &Ast) -> bool {
+        match ast {
+            Ast::DiscriminatorStruct { .. } | Ast::DiscriminatorInterface { .. } => true,
+            Ast::Documented(_, ast) => self.uses_discriminator(ast),
+            Ast::Sequence(asts) => asts.iter().any(|ast| self.uses_discriminator(ast)),
+            Ast::Files(files) => files.iter().any(|(_, ast)| self.uses_discriminator(ast)),
+            _ => false,
+        }
+    }
+
+    fn uses_nullable(&self, ast: &Ast) -> bool {
+        self.uses_type(ast, |ast| matches!(ast, Ast::Nullable(_)))
+    }
+
+    fn uses_timestamp(&self, ast: &Ast) -> bool {
+        self.uses_type(ast, |ast| matches!(ast, Ast::Timestamp))
+    }
+
+    // Whether any type expression matching a predicate appears in a
+    // declaration, or a sequence of them.
+    fn uses_type(&self, ast: &Ast, is: fn(&Ast) -> bool) -> bool {
+        if is(ast) {
+            return true;
+        }
+
+        match ast {
+            Ast::Documented(_, ast)
+            | Ast::Array(ast)
+            | Ast::Map(ast)
+            | Ast::Pointer(ast)
+            | Ast::Nullable(ast)
+            | Ast::Defined(_, ast)
+            | Ast::Typedef(_, _, ast) => self.uses_type(ast, is),
+            Ast::Struct(s) => s
+                .properties
+                .iter()
+                .any(|prop| self.uses_type(&prop.ast, is)),
+            Ast::DiscriminatorStruct { variants, .. }
+            | Ast::DiscriminatorInterface { variants, .. } => variants
+                .iter()
+                .flat_map(|variant| &variant.properties)
+                .any(|prop| self.uses_type(&prop.ast, is)),
+            Ast::Sequence(asts) => asts.iter().any(|ast| self.uses_type(ast, is)),
+            Ast::Files(files) => files.iter().any(|(_, ast)| self.uses_type(ast, is)),
+            _ => false,
+        }
+    }
+
+    // Starts a file of the package generated for `ast`, with a header.
+    fn start_file(&self, imports: &BTreeSet<&str>, ast: &Ast) -> GoWriter {
+        let mut w = GoWriter::new();
+
+        // Older versions of Go ignore omitzero, and so encode an absent
+        // Nullable as null, rather than leaving it out.
+        let mut header = target::header(self.source.as_deref());
+        if self.uses_nullable(ast) {
+            header.push(format!("Requires Go {} or later.", NULLABLE_GO_VERSION));
+        }
+
+        // Kept apart from the package clause, so that it is not taken for the
+        // package's documentation.
+        w.doc(&header.join("\n"));
+        w.blank();
+        w.line(format!("package {}", self.pkg_name));
+        w.blank();
+        self.serialize_imports(&mut w, imports);
+
+        w
+    }
+
+    // The names of the declarations `serialize_helpers` writes out.
+    fn helper_names(&self, decls: &Decls, ast: &Ast) -> Vec<String> {
+        let mut names = vec![];
+        if self.uses_discriminator(ast) {
+            names.push("ErrUnknownVariant".to_owned());
+            names.push("marshalVariant".to_owned());
+        }
+        if self.uses_enum(ast) {
+            names.push("InvalidEnumValueError".to_owned());
+        }
+        if !decls.validated.is_empty() {
+            names.push("ValidationError".to_owned());
+            names.push("ValidationErrors".to_owned());
+        }
+        if decls.validates_in_loop(ast, Loop::Map) {
+            names.push("pointerEscaper".to_owned());
+        }
+        if decls.decodes_integers(ast) {
+            names.push("decodeInteger".to_owned());
+        }
+        if self.uses_nullable(ast) {
+            names.push("Nullable".to_owned());
+        }
+        if self.uses_timestamp(ast) {
+            names.push(TIMESTAMP.to_owned());
+        }
+
+        names
+    }
 
-                Ok(Ast::Identifier(enum_name))
+    // The names of the package-level declarations made for the schema's types.
+    fn declared_names(&self, names: &mut Vec<String>, ast: &Ast) {
+        match ast {
+            Ast::Struct(s) => names.push(s.name.clone()),
+            Ast::Enum(name, _, members) => {
+                names.push(name.clone());
+                names.push(format!("All{}Values", name));
+                names.extend(members.iter().map(|member| member.name.clone()));
             }
-            Form::Elements(schema) => Ok(Ast::Array(Box::new(
-                self.transform_subschema(seq, name, schema)?,
-            ))),
-            Form::Properties {
-                required, optional, ..
+            Ast::DiscriminatorStruct { name, variants, .. } => {
+                names.push(name.clone());
+                names.extend(variants.iter().map(|variant| variant.name.clone()));
+            }
+            Ast::DiscriminatorInterface {
+                name,
+                interface,
+                variants,
+                ..
             } => {
-                let mut props = Vec::new();
-                for (prop, schema) in required {
-                    name.push(prop);
-                    let ast = self.transform_subschema(seq, name, schema)?;
-                    name.pop();
-
-                    props.push(Property {
-                        name: prop.to_pascal_case(),
-                        required: true,
-                        json: prop.to_owned(),
-                        ast,
-                    })
+                names.push(name.clone());
+                names.push(interface.clone());
+                names.extend(variants.iter().map(|variant| variant.name.clone()));
+            }
+            Ast::Const(name, _, _) | Ast::Defined(name, _) | Ast::Typedef(name, _, _) => {
+                names.push(name.clone())
+            }
+            Ast::Documented(_, ast) => self.declared_names(names, ast),
+            Ast::Sequence(asts) => {
+                for ast in asts {
+                    self.declared_names(names, ast);
                 }
-
-                for (prop, schema) in optional {
-                    name.push(prop);
-                    let ast = self.transform_subschema(seq, name, schema)?;
-                    name.pop();
-
-                    props.push(Property {
-                        name: prop.to_pascal_case(),
-                        required: false,
-                        json: prop.to_owned(),
-                        ast,
-                    });
+            }
+            Ast::Files(files) => {
+                for (_, ast) in files {
+                    self.declared_names(names, ast);
                 }
+            }
+            _ => {}
+        }
+    }
 
-                let id = self.name(name);
-                seq.push(Ast::Struct(id.clone(), props));
-                Ok(Ast::Identifier(id))
-            }
-            Form::Discriminator(tag, mapping) => {
-                // Create the enum for the values the tag can take on.
-                name.push(tag);
-                let tag_enum_name = self.name(name);
-                seq.push(Ast::Typedef(tag_enum_name.clone(), Box::new(Ast::String)));
-                name.pop();
-
-                // Loop over the mapping values. For each one, we must generate
-                // both a value for the tag enum, and a variant struct.
-                let mut variants = Vec::new();
-                for (tag_value, variant) in mapping {
-                    // Add a value for the tag enum.
-                    name.push(tag);
-                    name.push(tag_value);
-                    let tag_variant_name = self.name(name);
-                    seq.push(Ast::Const(
-                        tag_variant_name,
-                        tag_enum_name.clone(),
-                        Box::new(Ast::StrConstant(tag_value.to_owned())),
-                    ));
-                    name.pop();
-                    name.pop();
-
-                    // Generate a variant struct. We can count on the variant
-                    // being of the properties.
-                    if let Form::Properties {
-                        required, optional, ..
-                    } = variant.form()
-                    {
-                        let mut props = Vec::new();
-                        for (prop, schema) in required {
-                            name.push(prop);
-                            let ast = self.transform_subschema(seq, name, schema)?;
-                            name.pop();
-
-                            props.push(Property {
-                                name: prop.to_pascal_case(),
-                                required: true,
-                                json: prop.to_owned(),
-                                ast,
-                            })
-                        }
-
-                        for (prop, schema) in optional {
-                            name.push(prop);
-                            let ast = self.transform_subschema(seq, name, schema)?;
-                            name.pop();
-
-                            props.push(Property {
-                                name: prop.to_pascal_case(),
-                                required: false,
-                                json: prop.to_owned(),
-                                ast,
-                            });
-                        }
-
-                        name.push(tag_value);
-                        let variant_name = self.name(name);
-                        variants.push(DiscriminatorVariant {
-                            name: variant_name,
-                            name_json: tag_value.to_owned(),
-                            properties: props,
-                        });
-                    }
+    // Writes out the declarations the generated types share, of those which are
+    // used.
+    fn serialize_helpers(&self, w: &mut GoWriter, decls: &Decls, ast: &Ast) {
+        if self.uses_discriminator(ast) {
+            w.line(format!(
+                "var ErrUnknownVariant = errors.New({})",
+                quote(&format!(
+                    "{}: unknown discriminator tag value",
+                    self.pkg_name
+                ))
+            ));
+            w.blank();
+            self.serialize_marshal_variant(w);
+            w.blank();
+        }
 
-                    name.pop();
-                }
+        if self.uses_enum(ast) {
+            self.serialize_enum_error(w);
+            w.blank();
+        }
+
+        if !decls.validated.is_empty() {
+            self.serialize_validation_error(w);
+            w.blank();
+        }
+
+        if decls.validates_in_loop(ast, Loop::Map) {
+            w.line("// pointerEscaper escapes an object's key for use in a JSON Pointer.");
+            w.line("var pointerEscaper = strings.NewReplacer(\"~\", \"~0\", \"/\", \"~1\")");
+            w.blank();
+        }
+
+        if decls.decodes_integers(ast) {
+            self.serialize_decode_integer(w);
+            w.blank();
+        }
 
-                let id = self.name(name);
-                seq.push(Ast::DiscriminatorStruct {
-                    name: id.clone(),
-                    tag: tag_enum_name,
-                    tag_short: tag.to_pascal_case(),
-                    tag_json: tag.to_owned(),
-                    variants,
-                });
-                Ok(Ast::Identifier(id))
+        if self.uses_nullable(ast) {
+            self.serialize_nullable(w);
+            w.blank();
+        }
+
+        if self.uses_timestamp(ast) {
+            self.serialize_timestamp(w);
+            w.blank();
+        }
+    }
+
+    fn serialize_enum_error(&self, w: &mut GoWriter) {
+        w.line("// InvalidEnumValueError is returned when encoding or decoding a value");
+        w.line("// which an enum does not allow.");
+        w.open("type InvalidEnumValueError struct {");
+        w.field(&["Type", "string"]);
+        w.field(&["Value", "string"]);
+        w.close("}");
+        w.blank();
+        w.open("func (e *InvalidEnumValueError) Error() string {");
+        w.line(format!(
+            "return fmt.Sprintf({}, e.Type, e.Value)",
+            quote(&format!("{}: invalid %s value: %q", self.pkg_name))
+        ));
+        w.close("}");
+    }
+
+    fn serialize_enum(&self, w: &mut GoWriter, name: &str, members: &[EnumMember]) {
+        w.line(format!("type {} string", name));
+        w.blank();
+
+        for member in members {
+            if let Some(description) = &member.description {
+                w.doc(description);
             }
-            Form::Values(schema) => Ok(Ast::Map(Box::new(
-                self.transform_subschema(seq, name, schema)?,
-            ))),
+            w.line(format!(
+                "const {} {} = {}",
+                member.name,
+                name,
+                quote(&member.json)
+            ));
+            w.blank();
         }
+
+        let names: Vec<_> = members.iter().map(|member| member.name.as_str()).collect();
+
+        w.line(format!(
+            "// All{}Values is every value {} can take on.",
+            name, name
+        ));
+        w.open(format!("var All{}Values = []{}{{", name, name));
+        for member in &names {
+            w.line(format!("{},", member));
+        }
+        w.close("}");
+        w.blank();
+
+        w.open(format!("func (v {}) String() string {{", name));
+        w.line("return string(v)");
+        w.close("}");
+        w.blank();
+
+        w.line(format!(
+            "// IsValid reports whether v is one of All{}Values.",
+            name
+        ));
+        w.open(format!("func (v {}) IsValid() bool {{", name));
+        w.line("switch v {");
+        w.open(format!("case {}:", names.join(", ")));
+        w.line("return true");
+        w.dedent();
+        w.line("}");
+        w.line("return false");
+        w.close("}");
+        w.blank();
+
+        w.open(format!(
+            "func (v {}) MarshalJSON() ([]byte, error) {{",
+            name
+        ));
+        w.open("if !v.IsValid() {");
+        w.line(format!(
+            "return nil, &InvalidEnumValueError{{Type: {}, Value: string(v)}}",
+            quote(name)
+        ));
+        w.close("}");
+        w.line("return json.Marshal(string(v))");
+        w.close("}");
+        w.blank();
+
+        w.open(format!(
+            "func (v *{}) UnmarshalJSON(b []byte) error {{",
+            name
+        ));
+        w.line("var s string");
+        w.open("if err := json.Unmarshal(b, &s); err != nil {");
+        w.line("return err");
+        w.close("}");
+        w.open(format!("if !{}(s).IsValid() {{", name));
+        w.line(format!(
+            "return &InvalidEnumValueError{{Type: {}, Value: s}}",
+            quote(name)
+        ));
+        w.close("}");
+        w.line(format!("*v = {}(s)", name));
+        w.line("return nil");
+        w.close("}");
     }
 
-    fn ensure_has_name(&self, name: Option<&str>, seq: &mut Vec<Ast>, ast: Ast) {
+    // Variants are not encoded by embedding them in a struct alongside the tag,
+    // since any MarshalJSON they have would be promoted to that struct, and the
+    // tag left out. Instead, the tag is added to whatever the variant encodes
+    // to.
+    fn serialize_marshal_variant(&self, w: &mut GoWriter) {
+        w.line("// marshalVariant encodes a discriminator's variant, along with its tag.");
+        w.open("func marshalVariant(tag, value string, variant interface{}) ([]byte, error) {");
+        w.line("b, err := json.Marshal(variant)");
+        w.open("if err != nil {");
+        w.line("return nil, err");
+        w.close("}");
+        w.blank();
+        w.line("var obj map[string]json.RawMessage");
+        w.open("if err := json.Unmarshal(b, &obj); err != nil {");
+        w.line("return nil, err");
+        w.close("}");
+        w.open("if obj[tag], err = json.Marshal(value); err != nil {");
+        w.line("return nil, err");
+        w.close("}");
+        w.line("return json.Marshal(obj)");
+        w.close("}");
+    }
+
+    fn serialize_discriminator_struct(
+        &self,
+        w: &mut GoWriter,
+        name: &str,
+        tag: &str,
+        tag_short: &str,
+        tag_json: &str,
+        variants: &[StructAst],
+    ) {
+        w.open(format!("type {} struct {{", name));
+        w.field(&[tag_short, tag, &format!("`json:{}`", quote(tag_json))]);
+        for variant in variants {
+            w.line(&variant.name);
+        }
+        w.close("}");
+        w.blank();
+
+        w.open(format!(
+            "func (v {}) MarshalJSON() ([]byte, error) {{",
+            name
+        ));
+        w.line(format!("switch v.{} {{", tag_short));
+        for variant in variants {
+            let tag_value = &variant.tag.as_ref().unwrap().1;
+            w.open(format!("case {}:", quote(tag_value)));
+            w.line(format!(
+                "return marshalVariant({}, {}, &v.{})",
+                quote(tag_json),
+                quote(tag_value),
+                variant.name
+            ));
+            w.dedent();
+        }
+        w.line("}");
+        w.line("return nil, ErrUnknownVariant");
+        w.close("}");
+        w.blank();
+
+        w.open(format!(
+            "func (v *{}) UnmarshalJSON(b []byte) error {{",
+            name
+        ));
+        w.line("var obj map[string]interface{}");
+        w.open("if err := json.Unmarshal(b, &obj); err != nil {");
+        w.line("return err");
+        w.close("}");
+        w.line(format!("tag, ok := obj[{}].(string)", quote(tag_json)));
+        w.open("if !ok {");
+        w.line("return ErrUnknownVariant");
+        w.close("}");
+        w.line(format!("v.{} = tag", tag_short));
+        w.line("switch tag {");
+        for variant in variants {
+            w.open(format!("case {}:", quote(&variant.tag.as_ref().unwrap().1)));
+            w.line(format!("return json.Unmarshal(b, &v.{})", variant.name));
+            w.dedent();
+        }
+        w.line("}");
+        w.line("return ErrUnknownVariant");
+        w.close("}");
+    }
+
+    fn serialize_discriminator_interface(
+        &self,
+        w: &mut GoWriter,
+        name: &str,
+        interface: &str,
+        tag_json: &str,
+        variants: &[StructAst],
+    ) {
+        let variant_names: Vec<_> = variants.iter().map(|v| v.name.as_str()).collect();
+
+        w.open(format!("type {} struct {{", name));
+        w.field(&["Value", interface]);
+        w.close("}");
+        w.blank();
+
+        w.line(format!(
+            "// {} is implemented by {}, and pointers to them, and nothing else.",
+            interface,
+            variant_names.join(", ")
+        ));
+        w.open(format!("type {} interface {{", interface));
+        w.line(format!("is{}()", interface));
+        w.close("}");
+        w.blank();
+
+        w.open(format!(
+            "func (v {}) MarshalJSON() ([]byte, error) {{",
+            name
+        ));
+        w.line("switch value := v.Value.(type) {");
+        for variant in variants {
+            let tag_value = &variant.tag.as_ref().unwrap().1;
+            let marshal = |operand: &str| {
+                format!(
+                    "return marshalVariant({}, {}, {})",
+                    quote(tag_json),
+                    quote(tag_value),
+                    operand
+                )
+            };
+
+            // The interface is implemented by pointers to variants as well.
+            w.open(format!("case {}:", variant.name));
+            w.line(marshal("&value"));
+            w.dedent();
+            w.open(format!("case *{}:", variant.name));
+            w.open("if value != nil {");
+            w.line(marshal("value"));
+            w.close("}");
+            w.dedent();
+        }
+        w.line("}");
+        w.line("return nil, ErrUnknownVariant");
+        w.close("}");
+        w.blank();
+
+        w.open(format!(
+            "func (v *{}) UnmarshalJSON(b []byte) error {{",
+            name
+        ));
+        w.open("var obj struct {");
+        w.field(&["Tag", "string", &format!("`json:{}`", quote(tag_json))]);
+        w.close("}");
+        w.open("if err := json.Unmarshal(b, &obj); err != nil {");
+        w.line("return err");
+        w.close("}");
+        w.blank();
+        w.line("switch obj.Tag {");
+        for variant in variants {
+            w.open(format!("case {}:", quote(&variant.tag.as_ref().unwrap().1)));
+            w.line(format!("var value {}", variant.name));
+            w.open("if err := json.Unmarshal(b, &value); err != nil {");
+            w.line("return err");
+            w.close("}");
+            w.line("v.Value = value");
+            w.line("return nil");
+            w.dedent();
+        }
+        w.line("}");
+        w.line("return ErrUnknownVariant");
+        w.close("}");
+    }
+
+    fn serialize_decode_integer(&self, w: &mut GoWriter) {
+        w.line("// decodeInteger returns the integer a JSON value is, if it is one within");
+        w.line("// [min, max]. Unlike encoding/json, JDDF allows an integer to be written");
+        w.line("// with a fractional part or exponent, such as 1.0 or 1e2.");
+        w.open("func decodeInteger(b json.RawMessage, min, max float64) (int64, bool) {");
+        w.line("f, err := strconv.ParseFloat(string(b), 64)");
+        w.open("if err != nil || f < min || f > max || f != float64(int64(f)) {");
+        w.line("return 0, false");
+        w.close("}");
+        w.line("return int64(f), true");
+        w.close("}");
+    }
+
+    fn serialize_nullable(&self, w: &mut GoWriter) {
+        w.line("// Nullable is an optional property which may also be null. Present is");
+        w.line("// false if the property is absent, and Value is nil if it is null.");
+        w.open("type Nullable[T any] struct {");
+        w.field(&["Value", "*T"]);
+        w.field(&["Present", "bool"]);
+        w.close("}");
+        w.blank();
+        w.open("func (n Nullable[T]) IsZero() bool {");
+        w.line("return !n.Present");
+        w.close("}");
+        w.blank();
+        w.open("func (n Nullable[T]) MarshalJSON() ([]byte, error) {");
+        w.line("return json.Marshal(n.Value)");
+        w.close("}");
+        w.blank();
+        w.open("func (n *Nullable[T]) UnmarshalJSON(b []byte) error {");
+        w.line("n.Present = true");
+        w.line("return json.Unmarshal(b, &n.Value)");
+        w.close("}");
+    }
+
+    fn serialize_timestamp(&self, w: &mut GoWriter) {
+        w.line(format!(
+            "// {} is an RFC 3339 timestamp. Text is what it was decoded from, which",
+            TIMESTAMP
+        ));
+        w.line("// is encoded in its place, unless Time no longer matches it.");
+        w.open(format!("type {} struct {{", TIMESTAMP));
+        w.field(&["Time", "time.Time"]);
+        w.field(&["Text", "string"]);
+        w.close("}");
+        w.blank();
+        w.open(format!(
+            "func (t {}) MarshalJSON() ([]byte, error) {{",
+            TIMESTAMP
+        ));
+        w.open("if parsed, err := time.Parse(time.RFC3339, t.Text); err == nil && parsed.Equal(t.Time) {");
+        w.line("return json.Marshal(t.Text)");
+        w.close("}");
+        w.line("return json.Marshal(t.Time.Format(time.RFC3339Nano))");
+        w.close("}");
+        w.blank();
+        w.open(format!(
+            "func (t *{}) UnmarshalJSON(b []byte) error {{",
+            TIMESTAMP
+        ));
+        w.line("var s string");
+        w.open("if err := json.Unmarshal(b, &s); err != nil {");
+        w.line("return err");
+        w.close("}");
+        w.line("parsed, err := time.Parse(time.RFC3339, s)");
+        w.open("if err != nil {");
+        w.line("return err");
+        w.close("}");
+        w.line("t.Time, t.Text = parsed, s");
+        w.line("return nil");
+        w.close("}");
+    }
+
+    // Go refuses to compile a file with unused imports, so only those the
+    // generated code refers to are collected. The helpers are kept apart from
+    // the declarations using them, since they may end up in another file.
+    fn helper_imports(&self, decls: &Decls, ast: &Ast) -> BTreeSet<&'static str> {
+        let mut imports = BTreeSet::new();
+        if self.uses_discriminator(ast) {
+            imports.insert("encoding/json");
+            imports.insert("errors");
+        }
+        if self.uses_enum(ast) {
+            imports.insert("fmt");
+        }
+        if !decls.validated.is_empty() {
+            imports.insert("fmt");
+            imports.insert("strings");
+        }
+        if decls.decodes_integers(ast) {
+            imports.insert("encoding/json");
+            imports.insert("strconv");
+        }
+        if self.uses_nullable(ast) {
+            imports.insert("encoding/json");
+        }
+        if self.uses_timestamp(ast) {
+            imports.insert("encoding/json");
+            imports.insert("time");
+        }
+
+        imports
+    }
+
+    fn decl_imports<'a>(&self, imports: &mut BTreeSet<&'a str>, decls: &Decls, ast: &'a Ast) {
+        self.imports(imports, ast);
+
+        // for the indices in instance paths
+        if decls.validates_in_loop(ast, Loop::Slice) {
+            imports.insert("fmt");
+        }
+
+        // for the numbers integers are decoded from
+        if decls.decodes_integers(ast) {
+            imports.insert("encoding/json");
+        }
+    }
+
+    fn imports<'a>(&self, imports: &mut BTreeSet<&'a str>, ast: &'a Ast) {
         match ast {
-            Ast::Identifier(_) => {}
+            Ast::Time => {
+                imports.insert("time");
+            }
+            Ast::Custom(_, Some(import)) => {
+                imports.insert(import);
+            }
+            Ast::Array(ast)
+            | Ast::Map(ast)
+            | Ast::Nullable(ast)
+            | Ast::Pointer(ast)
+            | Ast::Defined(_, ast)
+            | Ast::Typedef(_, _, ast)
+            | Ast::Documented(_, ast) => self.imports(imports, ast),
+            Ast::Struct(s) => self.struct_imports(imports, s),
+            Ast::Enum(..) => {
+                imports.insert("encoding/json");
+            }
+            Ast::DiscriminatorStruct { variants, .. }
+            | Ast::DiscriminatorInterface { variants, .. } => {
+                imports.insert("encoding/json");
+                for variant in variants {
+                    self.struct_imports(imports, variant);
+                }
+            }
+            Ast::Sequence(asts) => {
+                for ast in asts {
+                    self.imports(imports, ast);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn struct_imports<'a>(&self, imports: &mut BTreeSet<&'a str>, s: &'a StructAst) {
+        match s.additional {
+            Additional::Reject => {
+                imports.insert("encoding/json");
+                imports.insert("fmt");
+            }
+            Additional::Capture => {
+                imports.insert("encoding/json");
+            }
+            Additional::Ignore => {
+                // to track which required properties are present
+                if s.properties.iter().any(|prop| prop.required) {
+                    imports.insert("encoding/json");
+                }
+            }
+        }
+
+        for prop in &s.properties {
+            self.imports(imports, &prop.ast);
+        }
+    }
+
+    fn serialize_imports(&self, w: &mut GoWriter, imports: &BTreeSet<&str>) {
+        if imports.is_empty() {
+            return;
+        }
+
+        // As goimports does, the standard library -- whose paths have no dot
+        // in their first element -- goes in a group before everything else.
+        let (std, other): (Vec<&str>, Vec<&str>) = imports
+            .iter()
+            .partition(|import| !import.split('/').next().unwrap().contains('.'));
+
+        w.open("import (");
+        for import in &std {
+            w.line(quote(import));
+        }
+        w.blank();
+        for import in &other {
+            w.line(quote(import));
+        }
+        w.close(")");
+        w.blank();
+    }
+
+    fn serialize_struct(&self, w: &mut GoWriter, decls: &Decls, s: &StructAst) {
+        let required: Vec<_> = s.properties.iter().filter(|prop| prop.required).collect();
+        let integers: Vec<_> = s
+            .properties
+            .iter()
+            .filter(|prop| decls.holds_integers(&prop.ast))
+            .collect();
+
+        if let Some(description) = &s.description {
+            w.doc(description);
+        }
+        if s.properties.is_empty() && s.additional != Additional::Capture {
+            w.line(format!("type {} struct{{}}", s.name));
+        } else {
+            w.open(format!("type {} struct {{", s.name));
+            for prop in &s.properties {
+                self.serialize_property(w, prop);
+            }
+            if s.additional == Additional::Capture {
+                w.blank();
+                w.line(
+                    "// Extra holds any properties besides those above, as they appeared in JSON.",
+                );
+                w.field(&["Extra", "map[string]json.RawMessage", "`json:\"-\"`"]);
+            }
+            if !required.is_empty() {
+                w.blank();
+                w.line("// the schema paths, relative to the struct's own, of the required");
+                w.line("// properties which were absent from the JSON it was decoded from");
+                w.field(&["missing", "[]string"]);
+            }
+            if !integers.is_empty() {
+                w.blank();
+                w.line("// the ways in which the integers in the JSON it was decoded from did not");
+                w.line("// satisfy the schema, with instance paths relative to the struct's own");
+                w.field(&["invalid", "[]ValidationError"]);
+            }
+            w.close("}");
+        }
+
+        self.serialize_struct_unmarshal(w, decls, s, &required, &integers);
+
+        if s.additional == Additional::Capture {
+            self.serialize_struct_marshal(w, s);
+        }
+
+        w.blank();
+        self.serialize_validate(w, &s.name);
+        w.blank();
+        self.serialize_validate_struct(w, decls, s, !required.is_empty(), !integers.is_empty());
+    }
+
+    fn serialize_struct_unmarshal(
+        &self,
+        w: &mut GoWriter,
+        decls: &Decls,
+        s: &StructAst,
+        required: &[&Property],
+        integers: &[&Property],
+    ) {
+        let tracks_keys = s.additional != Additional::Ignore || !required.is_empty();
+        if !tracks_keys && integers.is_empty() {
+            return;
+        }
+
+        w.blank();
+        w.open(format!(
+            "func (v *{}) UnmarshalJSON(b []byte) error {{",
+            s.name
+        ));
+        if tracks_keys {
+            self.serialize_struct_keys(w, s, required);
+            w.blank();
+        }
+        w.line(format!("type alias {}", s.name));
+        if integers.is_empty() {
+            w.line("return json.Unmarshal(b, (*alias)(v))");
+        } else {
+            self.serialize_struct_integers(w, decls, s, integers);
+        }
+        w.close("}");
+    }
+
+    // Writes out the checks of the keys of the object a struct is decoded
+    // from: that they are all known, and that the required ones are present.
+    fn serialize_struct_keys(&self, w: &mut GoWriter, s: &StructAst, required: &[&Property]) {
+        // Properties the struct has a field for. A variant is also decoded
+        // from an object containing its tag.
+        let known: Vec<_> = s
+            .tag
+            .iter()
+            .map(|(json, _)| json)
+            .chain(s.properties.iter().map(|prop| &prop.json))
+            .map(|json| quote(json))
+            .collect();
+
+        w.line("var obj map[string]json.RawMessage");
+        w.open("if err := json.Unmarshal(b, &obj); err != nil {");
+        w.line("return err");
+        w.close("}");
+        if s.additional != Additional::Ignore {
+            if s.additional == Additional::Capture {
+                w.line("v.Extra = nil");
+                w.open("for key, value := range obj {");
+            } else {
+                w.open("for key := range obj {");
+            }
+            if known.is_empty() {
+                self.serialize_unknown_key(w, s);
+            } else {
+                w.line("switch key {");
+                w.line(format!("case {}:", known.join(", ")));
+                w.open("default:");
+                self.serialize_unknown_key(w, s);
+                w.dedent();
+                w.line("}");
+            }
+            w.close("}");
+        }
+        if !required.is_empty() {
+            w.blank();
+            w.line("v.missing = nil");
+            for prop in required {
+                w.open(format!("if _, ok := obj[{}]; !ok {{", quote(&prop.json)));
+                w.line(format!(
+                    "v.missing = append(v.missing, {})",
+                    quote(&format!("/properties/{}", ir::pointer_token(&prop.json)))
+                ));
+                w.close("}");
+            }
+        }
+    }
+
+    // Writes out the decoding of a struct whose properties hold integers. Each
+    // is first decoded as a json.RawMessage, by a field of the same name which
+    // hides the struct's own, and then checked against its type.
+    fn serialize_struct_integers(
+        &self,
+        w: &mut GoWriter,
+        decls: &Decls,
+        s: &StructAst,
+        integers: &[&Property],
+    ) {
+        w.open("var numbers struct {");
+        w.field(&["alias"]);
+        for prop in integers {
+            w.field(&[
+                &prop.name,
+                &self.serialize_number_type(decls, &prop.ast),
+                &json_tag(&prop.json, ""),
+            ]);
+        }
+        w.close("}");
+        w.line("numbers.alias = alias(*v)");
+        w.open("if err := json.Unmarshal(b, &numbers); err != nil {");
+        w.line("return err");
+        w.close("}");
+        w.line(format!("*v = {}(numbers.alias)", s.name));
+        w.blank();
+        w.line("v.invalid = nil");
+        for prop in integers {
+            let keyword = if prop.required {
+                "properties"
+            } else {
+                "optionalProperties"
+            };
+            let schema_path = format!(
+                "{}/{}/{}",
+                s.schema_path,
+                keyword,
+                ir::pointer_token(&prop.json)
+            );
+            let path = quote(&format!("/{}", ir::pointer_token(&prop.json)));
+
+            // An absent property is reported as missing, not as the wrong type.
+            let absent = prop.required && integer_range(decls.resolve(&prop.ast)).is_some();
+            if absent {
+                w.open(format!("if _, ok := obj[{}]; ok {{", quote(&prop.json)));
+            }
+            self.serialize_integers(
+                w,
+                decls,
+                &format!("v.{}", prop.name),
+                &format!("numbers.{}", prop.name),
+                &path,
+                &schema_path,
+                &prop.ast,
+                0,
+            );
+            if absent {
+                w.close("}");
+            }
+        }
+        w.line("return nil");
+    }
+
+    // The type a value is first decoded into, with json.RawMessage in place of
+    // each integer.
+    fn serialize_number_type(&self, decls: &Decls, ast: &Ast) -> String {
+        match decls.resolve(ast) {
+            Ast::Pointer(ast) => format!("*{}", self.serialize_number_type(decls, ast)),
+            Ast::Nullable(ast) => format!("Nullable[{}]", self.serialize_number_type(decls, ast)),
+            Ast::Array(ast) => format!("[]{}", self.serialize_number_type(decls, ast)),
+            Ast::Map(ast) => format!("map[string]{}", self.serialize_number_type(decls, ast)),
+            _ => "json.RawMessage".to_owned(),
+        }
+    }
+
+    // Writes out the assignment to one expression of the value of another,
+    // decoded into the type `serialize_number_type` gives, with each integer
+    // checked along the way. Values in a slice or map are assigned in a loop,
+    // and depth is how many such loops the expressions are within.
+    #[allow(clippy::too_many_arguments)]
+    fn serialize_integers(
+        &self,
+        w: &mut GoWriter,
+        decls: &Decls,
+        target: &str,
+        source: &str,
+        path: &str,
+        schema_path: &str,
+        ast: &Ast,
+        depth: usize,
+    ) {
+        let suffix = if depth == 0 {
+            "".to_owned()
+        } else {
+            depth.to_string()
+        };
+
+        let (resolved, schema_path) = decls.resolve_at(ast, schema_path);
+        match resolved {
+            Ast::Nullable(inner) => {
+                w.line(format!(
+                    "{} = {}{{Present: {}.Present}}",
+                    target,
+                    self.serialize_type(resolved),
+                    source
+                ));
+                w.open(format!("if {}.Value != nil {{", source));
+                w.line(format!(
+                    "{}.Value = new({})",
+                    target,
+                    self.serialize_type(inner)
+                ));
+                self.serialize_integers(
+                    w,
+                    decls,
+                    &format!("*{}.Value", target),
+                    &format!("*{}.Value", source),
+                    path,
+                    schema_path,
+                    inner,
+                    depth,
+                );
+                w.close("}");
+            }
+            Ast::Pointer(inner) | Ast::Array(inner) | Ast::Map(inner) => {
+                w.open(format!("if {} == nil {{", source));
+                w.line(format!("{} = nil", target));
+                w.dedent();
+                w.open("} else {");
+                match resolved {
+                    Ast::Pointer(_) => {
+                        w.line(format!("{} = new({})", target, self.serialize_type(inner)));
+                        self.serialize_integers(
+                            w,
+                            decls,
+                            &format!("*{}", target),
+                            &format!("*{}", source),
+                            path,
+                            schema_path,
+                            inner,
+                            depth,
+                        );
+                    }
+                    _ => {
+                        let (key, keyword, token) = match resolved {
+                            Ast::Array(_) => {
+                                let i = format!("i{}", suffix);
+                                let token = format!("fmt.Sprint({})", i);
+                                (i, "elements", token)
+                            }
+                            _ => {
+                                let k = format!("k{}", suffix);
+                                let token = format!("pointerEscaper.Replace({})", k);
+                                (k, "values", token)
+                            }
+                        };
+                        let value = format!("x{}", suffix);
+
+                        w.line(format!(
+                            "{} = make({}, len({}))",
+                            target,
+                            self.serialize_type(resolved),
+                            source
+                        ));
+                        w.open(format!("for {}, {} := range {} {{", key, value, source));
+                        self.serialize_integers(
+                            w,
+                            decls,
+                            &index(target, &key),
+                            &value,
+                            &append_path(path, &token, " + "),
+                            &format!("{}/{}", schema_path, keyword),
+                            inner,
+                            depth + 1,
+                        );
+                        w.close("}");
+                    }
+                }
+                w.close("}");
+            }
             _ => {
-                let id = self.name(&[name.unwrap_or(&self.root_name)]);
-                seq.push(Ast::Typedef(id, Box::new(ast)));
+                let (min, max) = integer_range(resolved).unwrap();
+                w.open(format!(
+                    "if n, ok := decodeInteger({}, {}, {}); ok {{",
+                    source, min, max
+                ));
+                w.line(format!("{} = {}(n)", target, self.serialize_type(resolved)));
+                w.dedent();
+                w.open("} else {");
+                w.line(format!(
+                    "v.invalid = append(v.invalid, ValidationError{{InstancePath: {}, SchemaPath: {}}})",
+                    path,
+                    quote(&format!("{}/type", schema_path))
+                ));
+                w.close("}");
             }
         }
     }
 
-    fn serialize_ast(&self, w: &mut Write, ast: &Ast) -> Result<(), Error> {
-        match ast {
-            Ast::EmptyInterface => write!(w, "interface{{}}")?,
-            Ast::Boolean => write!(w, "bool")?,
-            Ast::Int8 => write!(w, "int8")?,
-            Ast::Uint8 => write!(w, "uint8")?,
-            Ast::Int16 => write!(w, "int16")?,
-            Ast::Uint16 => write!(w, "uint16")?,
-            Ast::Int32 => write!(w, "int32")?,
-            Ast::Uint32 => write!(w, "uint32")?,
-            Ast::Float32 => write!(w, "float32")?,
-            Ast::Float64 => write!(w, "float64")?,
-            Ast::String => write!(w, "string")?,
-            Ast::Time => write!(w, "time.Time")?,
-            Ast::StrConstant(s) => write!(w, "{:?}", s)?,
-            Ast::Const(name, ty, ast) => {
-                write!(w, "const {} {} = ", name, ty)?;
-                self.serialize_ast(w, ast)?;
-                writeln!(w)?;
+    fn serialize_struct_marshal(&self, w: &mut GoWriter, s: &StructAst) {
+        w.blank();
+        w.open(format!(
+            "func (v {}) MarshalJSON() ([]byte, error) {{",
+            s.name
+        ));
+        w.line(format!("type alias {}", s.name));
+        w.line("b, err := json.Marshal(alias(v))");
+        w.open("if err != nil {");
+        w.line("return nil, err");
+        w.close("}");
+        w.blank();
+        w.line("var obj map[string]json.RawMessage");
+        w.open("if err := json.Unmarshal(b, &obj); err != nil {");
+        w.line("return nil, err");
+        w.close("}");
+        w.open("for key, value := range v.Extra {");
+        w.open("if _, ok := obj[key]; !ok {");
+        w.line("obj[key] = value");
+        w.close("}");
+        w.close("}");
+        w.line("return json.Marshal(obj)");
+        w.close("}");
+    }
+
+    fn serialize_unknown_key(&self, w: &mut GoWriter, s: &StructAst) {
+        if s.additional == Additional::Capture {
+            w.open("if v.Extra == nil {");
+            w.line("v.Extra = map[string]json.RawMessage{}");
+            w.close("}");
+            w.line("v.Extra[key] = value");
+        } else {
+            w.line(format!(
+                "return fmt.Errorf({}, key)",
+                quote(&format!(
+                    "{}: unknown property %q in {}",
+                    self.pkg_name, s.name
+                ))
+            ));
+        }
+    }
+
+    fn serialize_property(&self, w: &mut GoWriter, prop: &Property) {
+        if let Some(description) = &prop.description {
+            w.doc(description);
+        }
+
+        // Without these, an absent property would come back out as null.
+        let options = if let Ast::Nullable(_) = prop.ast {
+            "omitzero"
+        } else if !prop.required {
+            "omitempty"
+        } else {
+            ""
+        };
+
+        w.field(&[
+            &prop.name,
+            &self.serialize_type(&prop.ast),
+            &json_tag(&prop.json, options),
+        ]);
+    }
+
+    fn serialize_validation_error(&self, w: &mut GoWriter) {
+        w.line("// ValidationError is a way in which a value does not satisfy the schema.");
+        w.line("// Both paths are JSON Pointers: InstancePath to the part of the value at");
+        w.line("// fault, and SchemaPath to the part of the schema it does not satisfy.");
+        w.open("type ValidationError struct {");
+        w.field(&["InstancePath", "string"]);
+        w.field(&["SchemaPath", "string"]);
+        w.close("}");
+        w.blank();
+        w.open("func (e ValidationError) Error() string {");
+        w.line(format!(
+            "return fmt.Sprintf({}, e.InstancePath, e.SchemaPath)",
+            quote(&format!(
+                "{}: value at %q does not satisfy schema at %q",
+                self.pkg_name
+            ))
+        ));
+        w.close("}");
+        w.blank();
+        w.line("// ValidationErrors is every way in which a value does not satisfy the schema.");
+        w.line("//");
+        w.line("// A struct checks the integers it holds as it is decoded, and reports any");
+        w.line("// which are out of range, or are not integers, among these. An integer");
+        w.line("// held by no struct is decoded by encoding/json alone, which fails to");
+        w.line("// decode it in those cases, and also where it is written as JDDF allows");
+        w.line("// but encoding/json does not, such as 1.0.");
+        w.line("type ValidationErrors []ValidationError");
+        w.blank();
+        w.open("func (e ValidationErrors) Error() string {");
+        w.line("msgs := make([]string, len(e))");
+        w.open("for i, err := range e {");
+        w.line("msgs[i] = err.Error()");
+        w.close("}");
+        w.line("return strings.Join(msgs, \"; \")");
+        w.close("}");
+    }
+
+    fn serialize_validate(&self, w: &mut GoWriter, name: &str) {
+        w.line("// Validate reports every way in which v does not satisfy the schema, as");
+        w.line("// ValidationErrors, or returns nil if there are none.");
+        w.open(format!("func (v {}) Validate() error {{", name));
+        w.line("var errs ValidationErrors");
+        w.line("v.validate(\"\", &errs)");
+        w.open("if len(errs) != 0 {");
+        w.line("return errs");
+        w.close("}");
+        w.line("return nil");
+        w.close("}");
+    }
+
+    fn serialize_validate_enum(&self, w: &mut GoWriter, name: &str, schema_path: &str) {
+        w.open(format!(
+            "func (v {}) validate(path string, errs *ValidationErrors) {{",
+            name
+        ));
+        w.open("if !v.IsValid() {");
+        w.line(format!(
+            "*errs = append(*errs, ValidationError{{InstancePath: path, SchemaPath: {}}})",
+            quote(&format!("{}/enum", schema_path))
+        ));
+        w.close("}");
+        w.close("}");
+    }
+
+    fn serialize_validate_discriminator(&self, w: &mut GoWriter, ast: &Ast) {
+        let (name, schema_path, variants) = match ast {
+            Ast::DiscriminatorStruct {
+                name,
+                schema_path,
+                variants,
+                ..
+            }
+            | Ast::DiscriminatorInterface {
+                name,
+                schema_path,
+                variants,
+                ..
+            } => (name, schema_path, variants),
+            _ => unreachable!("{:?} is not a discriminator", ast),
+        };
+
+        self.serialize_validate(w, name);
+        w.blank();
+
+        w.open(format!(
+            "func (v {}) validate(path string, errs *ValidationErrors) {{",
+            name
+        ));
+        if let Ast::DiscriminatorStruct {
+            tag_short,
+            tag_json,
+            ..
+        } = ast
+        {
+            w.line(format!("switch v.{} {{", tag_short));
+            for variant in variants {
+                w.open(format!("case {}:", quote(&variant.tag.as_ref().unwrap().1)));
+                w.line(format!("v.{}.validate(path, errs)", variant.name));
+                w.dedent();
+            }
+            w.open("default:");
+            w.line(format!(
+                "*errs = append(*errs, ValidationError{{InstancePath: path + {}, SchemaPath: {}}})",
+                quote(&format!("/{}", ir::pointer_token(tag_json))),
+                quote(&format!("{}/discriminator/mapping", schema_path))
+            ));
+            w.dedent();
+            w.line("}");
+        } else {
+            w.line("switch value := v.Value.(type) {");
+            for variant in variants {
+                w.open(format!("case {}:", variant.name));
+                w.line("value.validate(path, errs)");
+                w.line("return");
+                w.dedent();
+                w.open(format!("case *{}:", variant.name));
+                w.open("if value != nil {");
+                w.line("value.validate(path, errs)");
+                w.line("return");
+                w.close("}");
+                w.dedent();
+            }
+            w.line("}");
+            w.line(format!(
+                "*errs = append(*errs, ValidationError{{InstancePath: path, SchemaPath: {}}})",
+                quote(&format!("{}/discriminator/tag", schema_path))
+            ));
+        }
+        w.close("}");
+    }
+
+    fn serialize_validate_struct(
+        &self,
+        w: &mut GoWriter,
+        decls: &Decls,
+        s: &StructAst,
+        tracks_missing: bool,
+        tracks_invalid: bool,
+    ) {
+        let validated: Vec<_> = s
+            .properties
+            .iter()
+            .filter(|prop| decls.needs_validation(&prop.ast))
+            .collect();
+
+        let signature = format!(
+            "func (v {}) validate(path string, errs *ValidationErrors)",
+            s.name
+        );
+        if !tracks_missing && !tracks_invalid && validated.is_empty() {
+            w.line(format!("{} {{}}", signature));
+            return;
+        }
+
+        w.open(format!("{} {{", signature));
+        if tracks_missing {
+            let schema_path = if s.schema_path.is_empty() {
+                "missing".to_owned()
+            } else {
+                format!("{} + missing", quote(&s.schema_path))
+            };
+
+            w.open("for _, missing := range v.missing {");
+            w.line(format!(
+                "*errs = append(*errs, ValidationError{{InstancePath: path, SchemaPath: {}}})",
+                schema_path
+            ));
+            w.close("}");
+        }
+        if tracks_invalid {
+            w.open("for _, invalid := range v.invalid {");
+            w.line("*errs = append(*errs, ValidationError{InstancePath: path + invalid.InstancePath, SchemaPath: invalid.SchemaPath})");
+            w.close("}");
+        }
+        for prop in validated {
+            let path = format!(
+                "path+{}",
+                quote(&format!("/{}", ir::pointer_token(&prop.json)))
+            );
+            self.serialize_validate_value(
+                w,
+                decls,
+                &format!("v.{}", prop.name),
+                &path,
+                &prop.ast,
+                0,
+            );
+        }
+        w.close("}");
+    }
+
+    // Writes out the validation of the value of an expression, whose instance
+    // path is given by another. Values in a slice or map are validated in a
+    // loop, and depth is how many such loops the expression is within.
+    fn serialize_validate_value(
+        &self,
+        w: &mut GoWriter,
+        decls: &Decls,
+        expr: &str,
+        path: &str,
+        ast: &Ast,
+        depth: usize,
+    ) {
+        let suffix = if depth == 0 {
+            "".to_owned()
+        } else {
+            depth.to_string()
+        };
+
+        match decls.resolve(ast) {
+            Ast::Identifier(_) => {
+                w.line(format!("{}.validate({}, errs)", expr, path));
+            }
+            resolved @ Ast::Pointer(ast) | resolved @ Ast::Nullable(ast) => {
+                let pointer = match resolved {
+                    Ast::Nullable(_) => format!("{}.Value", expr),
+                    _ => expr.to_owned(),
+                };
+
+                // Methods can be called through a pointer, but anything else
+                // has to be dereferenced.
+                let value = match decls.resolve(ast) {
+                    Ast::Identifier(_) => pointer.clone(),
+                    _ => format!("*{}", pointer),
+                };
+
+                w.open(format!("if {} != nil {{", pointer));
+                self.serialize_validate_value(w, decls, &value, path, ast, depth);
+                w.close("}");
             }
             Ast::Array(ast) => {
-                write!(w, "[]")?;
-                self.serialize_ast(w, ast)?;
+                let (index, value) = (format!("i{}", suffix), format!("x{}", suffix));
+                w.open(format!("for {}, {} := range {} {{", index, value, expr));
+                self.serialize_validate_value(
+                    w,
+                    decls,
+                    &value,
+                    &append_path(path, &format!("fmt.Sprint({})", index), "+"),
+                    ast,
+                    depth + 1,
+                );
+                w.close("}");
             }
             Ast::Map(ast) => {
-                write!(w, "map[string]")?;
-                self.serialize_ast(w, ast)?;
-            }
-            Ast::Struct(name, props) => {
-                writeln!(w, "type {} struct {{", name)?;
-                for prop in props {
-                    let Property {
-                        name,
-                        required,
-                        json,
-                        ast,
-                    } = prop;
-
-                    write!(w, "\t{} {}", name, if *required { "" } else { "*" })?;
-                    self.serialize_ast(w, ast)?;
-                    writeln!(w, " `json:\"{}\"`", json)?;
-                }
-                writeln!(w, "}}")?;
+                let (key, value) = (format!("k{}", suffix), format!("x{}", suffix));
+                w.open(format!("for {}, {} := range {} {{", key, value, expr));
+                self.serialize_validate_value(
+                    w,
+                    decls,
+                    &value,
+                    &append_path(path, &format!("pointerEscaper.Replace({})", key), "+"),
+                    ast,
+                    depth + 1,
+                );
+                w.close("}");
+            }
+            _ => {}
+        }
+    }
+
+    // Writes out a top-level declaration, or a sequence of them.
+    fn serialize_decl(&self, w: &mut GoWriter, decls: &Decls, ast: &Ast) {
+        match ast {
+            Ast::Const(name, ty, ast) => {
+                w.line(format!(
+                    "const {} {} = {}",
+                    name,
+                    ty,
+                    self.serialize_type(ast)
+                ));
+            }
+            Ast::Struct(s) => self.serialize_struct(w, decls, s),
+            Ast::Enum(name, schema_path, members) => {
+                self.serialize_enum(w, name, members);
+                w.blank();
+                self.serialize_validate_enum(w, name, schema_path);
             }
             Ast::DiscriminatorStruct {
                 name,
-                tag_short,
                 tag,
+                tag_short,
                 tag_json,
                 variants,
+                ..
             } => {
-                writeln!(w, "type {} struct {{", name)?;
-                writeln!(w, "\t{} {} `json:{:?}`", tag_short, tag, tag_json)?;
-                for variant in variants {
-                    writeln!(w, "\t{}", variant.name)?;
-                }
-                writeln!(w, "}}")?;
-                writeln!(w)?;
+                self.serialize_discriminator_struct(w, name, tag, tag_short, tag_json, variants);
+                w.blank();
+                self.serialize_validate_discriminator(w, ast);
 
-                writeln!(w, "func (v {}) MarshalJSON() ([]byte, error) {{", name)?;
-                writeln!(w, "\tswitch v.{} {{", tag_short)?;
                 for variant in variants {
-                    writeln!(w, "\tcase {:?}:", variant.name_json)?;
-                    writeln!(w, "\t\treturn json.Marshal(struct {{ Tag string `json:{:?}`; {} }}{{ Tag: {:?}, {}: v.{} }});", tag_json, variant.name, variant.name_json, variant.name, variant.name)?;
+                    w.blank();
+                    self.serialize_struct(w, decls, variant);
                 }
-                writeln!(w, "\t}}")?;
-                writeln!(w, "\treturn nil, ErrUnknownVariant")?;
-                writeln!(w, "}}")?;
+            }
+            Ast::DiscriminatorInterface {
+                name,
+                interface,
+                tag_json,
+                variants,
+                ..
+            } => {
+                self.serialize_discriminator_interface(w, name, interface, tag_json, variants);
+                w.blank();
+                self.serialize_validate_discriminator(w, ast);
 
-                writeln!(w, "func (v *{}) UnmarshalJSON(b []byte) error {{", name)?;
-                writeln!(w, "\tvar obj map[string]interface{{}}")?;
-                writeln!(
-                    w,
-                    "\tif err := json.Unmarshal(b, &obj); err != nil {{ return err }}"
-                )?;
-                writeln!(w, "\ttag, ok := obj[{:?}].(string)", tag_json)?;
-                writeln!(w, "\tif !ok {{ return ErrUnknownVariant }}")?;
-                writeln!(w, "\tv.{} = tag", tag_short)?;
-                writeln!(w, "\tswitch tag {{")?;
                 for variant in variants {
-                    writeln!(w, "\tcase {:?}:", variant.name_json)?;
-                    writeln!(w, "\t\treturn json.Unmarshal(b, &v.{})", variant.name)?;
+                    w.blank();
+                    self.serialize_struct(w, decls, variant);
+                    w.blank();
+                    w.line(format!("func ({}) is{}() {{}}", variant.name, interface));
                 }
-                writeln!(w, "\t}}")?;
-                writeln!(w, "\treturn ErrUnknownVariant")?;
-                writeln!(w, "}}")?;
-
-                for variant in variants {
-                    writeln!(w, "type {} struct {{", variant.name)?;
-                    for prop in &variant.properties {
-                        let Property {
-                            name,
-                            required,
-                            json,
-                            ast,
-                        } = prop;
-
-                        write!(w, "\t{} {}", name, if *required { "" } else { "*" })?;
-                        self.serialize_ast(w, ast)?;
-                        writeln!(w, " `json:\"{}\"`", json)?;
-                    }
-                    writeln!(w, "}}")?;
+            }
+            Ast::Documented(doc, ast) => {
+                w.doc(doc);
+                self.serialize_decl(w, decls, ast);
+            }
+            Ast::Defined(name, ast) => {
+                w.line(format!("type {} {}", name, self.serialize_type(ast)));
+            }
+            Ast::Typedef(name, _, ast) => {
+                w.line(format!("type {} = {}", name, self.serialize_type(ast)));
+            }
+            Ast::Sequence(asts) => {
+                for ast in asts {
+                    self.serialize_decl(w, decls, ast);
+                    w.blank();
                 }
             }
-            Ast::Identifier(id) => write!(w, "{}", id)?,
-            Ast::Typedef(name, ast) => {
-                write!(w, "type {} = ", name)?;
-                self.serialize_ast(w, ast)?;
-                writeln!(w)?;
+            _ => unreachable!("{:?} is not a declaration", ast),
+        }
+    }
+
+    // A type, or the value of a constant, as it appears within a declaration.
+    fn serialize_type(&self, ast: &Ast) -> String {
+        match ast {
+            Ast::EmptyInterface => "interface{}".to_owned(),
+            Ast::Boolean => "bool".to_owned(),
+            Ast::Int8 => "int8".to_owned(),
+            Ast::Uint8 => "uint8".to_owned(),
+            Ast::Int16 => "int16".to_owned(),
+            Ast::Uint16 => "uint16".to_owned(),
+            Ast::Int32 => "int32".to_owned(),
+            Ast::Uint32 => "uint32".to_owned(),
+            Ast::Float32 => "float32".to_owned(),
+            Ast::Float64 => "float64".to_owned(),
+            Ast::String => "string".to_owned(),
+            Ast::Time => "time.Time".to_owned(),
+            Ast::Timestamp => TIMESTAMP.to_owned(),
+            Ast::Custom(name, _) => name.clone(),
+            Ast::StrConstant(s) => quote(s),
+            Ast::Array(ast) => format!("[]{}", self.serialize_type(ast)),
+            Ast::Map(ast) => format!("map[string]{}", self.serialize_type(ast)),
+            Ast::Pointer(ast) => format!("*{}", self.serialize_type(ast)),
+            Ast::Nullable(ast) => format!("Nullable[{}]", self.serialize_type(ast)),
+            Ast::Identifier(id) => id.clone(),
+            _ => unreachable!("{:?} is not a type", ast),
+        }
+    }
+}
+
+/// Go's keywords, none of which can be used as an identifier.
+const KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+
+    chars.all(|c| c.is_alphanumeric() || c == '_') && !KEYWORDS.contains(&s)
+}
+
+/// The name of an exported struct field for a JSON property.
+///
+/// Go keywords are all lower-case, so they can never collide with an exported
+/// name. A name which would not start with a letter is prefixed with one, so
+/// that it is both valid and exported.
+fn field_name(json: &str) -> String {
+    let name = json.to_pascal_case();
+    match name.chars().next() {
+        Some(c) if c.is_alphabetic() => name,
+        _ => format!("X{}", name),
+    }
+}
+
+/// The least and greatest values of an integer type, or None if it is not one.
+fn integer_range(ast: &Ast) -> Option<(i64, i64)> {
+    match ast {
+        Ast::Int8 => Some((i8::MIN.into(), i8::MAX.into())),
+        Ast::Uint8 => Some((0, u8::MAX.into())),
+        Ast::Int16 => Some((i16::MIN.into(), i16::MAX.into())),
+        Ast::Uint16 => Some((0, u16::MAX.into())),
+        Ast::Int32 => Some((i32::MIN.into(), i32::MAX.into())),
+        Ast::Uint32 => Some((0, u32::MAX.into())),
+        _ => None,
+    }
+}
+
+/// An expression indexing into another, which may be dereferencing a pointer.
+fn index(expr: &str, index: &str) -> String {
+    if expr.starts_with('*') {
+        format!("({})[{}]", expr, index)
+    } else {
+        format!("{}[{}]", expr, index)
+    }
+}
+
+/// A struct tag naming a JSON property, with the given options.
+fn json_tag(json: &str, options: &str) -> String {
+    // A tag of just "-" would leave the field out entirely.
+    let tag = match (json, options) {
+        ("-", "") => "-,".to_owned(),
+        (_, "") => json.to_owned(),
+        _ => format!("{},{}", json, options),
+    };
+
+    format!("`json:{}`", quote(&tag))
+}
+
+/// Whether encoding/json would take `json` as the name in a struct tag. It
+/// ignores names which are empty, or hold anything other than letters, digits
+/// and some ASCII punctuation, and uses the field's own name in their place.
+fn is_tag_name(json: &str) -> bool {
+    !json.is_empty()
+        && json
+            .chars()
+            .all(|c| c.is_alphanumeric() || "!#$%&()*+-./:;<=>?@[]^_{|}~ ".contains(c))
+}
+
+/// An instance path with another token on the end of it, where both are Go
+/// expressions. gofmt spaces out the + operator in some places but not others,
+/// so it is written as given.
+fn append_path(path: &str, token: &str, plus: &str) -> String {
+    // If the path ends in a string literal, the slash goes on the end of it.
+    match path.strip_suffix('"') {
+        Some(path) => format!("{}/\"{}{}", path, plus, token),
+        None => format!("{}{}\"/\"{}{}", path, plus, plus, token),
+    }
+}
+
+/// Fails unless `file` names a Go source file directly within the output
+/// directory, which the go tool would build as part of the package. The go
+/// tool ignores files starting with a dot or underscore, and treats those
+/// ending in _test.go as tests.
+fn check_file_name(file: &str) -> Result<(), Error> {
+    if !file.ends_with(".go")
+        || file.ends_with("_test.go")
+        || file.starts_with('.')
+        || file.starts_with('_')
+        || file.contains(['/', '\\'])
+    {
+        return Err(format_err!("{:?} is not a valid Go file name", file));
+    }
+
+    Ok(())
+}
+
+/// A Go string literal with the given contents.
+fn quote(s: &str) -> String {
+    // JSON string escapes are a subset of Go's.
+    serde_json::to_string(s).unwrap()
+}
+
+/// The types declared in a file, for working out how a value of each of them
+/// is validated.
+struct Decls<'a> {
+    // the types which have a validate method
+    validated: HashSet<&'a str>,
+    // the aliases, and the schema paths and types they stand for
+    aliases: HashMap<&'a str, (&'a str, &'a Ast)>,
+}
+
+impl<'a> Decls<'a> {
+    fn new(ast: &'a Ast) -> Self {
+        let mut decls = Decls {
+            validated: HashSet::new(),
+            aliases: HashMap::new(),
+        };
+        decls.add(ast);
+        decls
+    }
+
+    fn add(&mut self, ast: &'a Ast) {
+        match ast {
+            Ast::Struct(s) => {
+                self.validated.insert(&s.name);
+            }
+            Ast::Enum(name, _, _)
+            | Ast::DiscriminatorStruct { name, .. }
+            | Ast::DiscriminatorInterface { name, .. } => {
+                self.validated.insert(name);
+            }
+            Ast::Typedef(name, schema_path, ast) => {
+                self.aliases.insert(name, (schema_path, ast));
             }
+            // Aliases which refer back to themselves are declared as defined
+            // types instead, and are not resolved. So every chain of aliases
+            // ends, and each of these holds only more of itself, with nothing
+            // in it to validate.
+            Ast::Defined(..) => {}
+            Ast::Documented(_, ast) => self.add(ast),
             Ast::Sequence(asts) => {
                 for ast in asts {
-                    self.serialize_ast(w, ast)?;
-                    writeln!(w)?;
+                    self.add(ast);
                 }
             }
+            Ast::Files(files) => {
+                for (_, ast) in files {
+                    self.add(ast);
+                }
+            }
+            _ => {}
         }
+    }
 
-        Ok(())
+    // The type an alias stands for, or the type itself if it is not one.
+    fn resolve<'b>(&'b self, ast: &'b Ast) -> &'b Ast {
+        self.resolve_at(ast, "").0
     }
 
-    fn name(&self, name: &[&str]) -> String {
-        name.join("_").to_pascal_case()
+    // As `resolve`, along with the schema path of what it resolves to, given
+    // that of the type itself.
+    fn resolve_at<'b>(&'b self, ast: &'b Ast, schema_path: &'b str) -> (&'b Ast, &'b str) {
+        match ast {
+            Ast::Identifier(name) => match self.aliases.get(name.as_str()) {
+                Some((schema_path, ast)) => self.resolve_at(ast, schema_path),
+                None => (ast, schema_path),
+            },
+            _ => (ast, schema_path),
+        }
+    }
+
+    // Whether a value of the type is or holds an integer, which a struct must
+    // decode for itself.
+    fn holds_integers(&self, ast: &Ast) -> bool {
+        match self.resolve(ast) {
+            Ast::Pointer(ast) | Ast::Nullable(ast) | Ast::Array(ast) | Ast::Map(ast) => {
+                self.holds_integers(ast)
+            }
+            ast => integer_range(ast).is_some(),
+        }
+    }
+
+    // Whether any struct decodes integers for itself.
+    fn decodes_integers(&self, ast: &Ast) -> bool {
+        self.any_property(ast, &|prop| self.holds_integers(&prop.ast))
+    }
+
+    fn needs_validation(&self, ast: &Ast) -> bool {
+        match self.resolve(ast) {
+            Ast::Identifier(name) => self.validated.contains(name.as_str()),
+            Ast::Pointer(ast) | Ast::Nullable(ast) | Ast::Array(ast) | Ast::Map(ast) => {
+                self.needs_validation(ast)
+            }
+            _ => false,
+        }
+    }
+
+    // Whether any value is validated, or decoded as an integer, in a loop of
+    // the given kind, and so needs its index or key made into an instance
+    // path.
+    fn validates_in_loop(&self, ast: &Ast, kind: Loop) -> bool {
+        self.any_property(ast, &|prop| self.loops(&prop.ast, kind))
+    }
+
+    // Whether any property of a struct, or of a discriminator's variants,
+    // matches a predicate.
+    fn any_property(&self, ast: &Ast, f: &dyn Fn(&Property) -> bool) -> bool {
+        match ast {
+            Ast::Struct(s) => s.properties.iter().any(f),
+            Ast::DiscriminatorStruct { variants, .. }
+            | Ast::DiscriminatorInterface { variants, .. } => variants
+                .iter()
+                .flat_map(|variant| &variant.properties)
+                .any(f),
+            Ast::Documented(_, ast) => self.any_property(ast, f),
+            Ast::Sequence(asts) => asts.iter().any(|ast| self.any_property(ast, f)),
+            Ast::Files(files) => files.iter().any(|(_, ast)| self.any_property(ast, f)),
+            _ => false,
+        }
+    }
+
+    fn loops(&self, ast: &Ast, kind: Loop) -> bool {
+        match (self.resolve(ast), kind) {
+            (Ast::Array(ast), Loop::Slice) | (Ast::Map(ast), Loop::Map) => {
+                self.needs_validation(ast) || self.holds_integers(ast) || self.loops(ast, kind)
+            }
+            (Ast::Pointer(ast), _)
+            | (Ast::Nullable(ast), _)
+            | (Ast::Array(ast), _)
+            | (Ast::Map(ast), _) => self.loops(ast, kind),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Loop {
+    Slice,
+    Map,
+}
+
+/// Lays out Go source as gofmt would.
+///
+/// Lines are indented with a tab for each block they are in. A blank line is
+/// only written between two lines of the same block, so there is never more
+/// than one in a row, nor one at either end of a block or of the file. Runs of
+/// struct fields have their names, types and tags aligned with spaces.
+struct GoWriter {
+    out: String,
+    indent: usize,
+    // whether the next line, if any, is to be preceded by a blank one
+    blank: bool,
+    // whether nothing has been written in the current block yet
+    start: bool,
+    // struct fields waiting to be aligned with those right after them
+    fields: Vec<Vec<String>>,
+}
+
+impl GoWriter {
+    fn new() -> Self {
+        GoWriter {
+            out: String::new(),
+            indent: 0,
+            blank: false,
+            start: true,
+            fields: Vec::new(),
+        }
+    }
+
+    fn line<S: AsRef<str>>(&mut self, line: S) {
+        self.flush_fields();
+        self.write(line.as_ref());
+    }
+
+    // Writes a line, and begins a block after it.
+    fn open<S: AsRef<str>>(&mut self, line: S) {
+        self.line(line);
+        self.indent();
+    }
+
+    // Ends a block, and writes a line after it.
+    fn close<S: AsRef<str>>(&mut self, line: S) {
+        self.dedent();
+        self.line(line);
+    }
+
+    fn indent(&mut self) {
+        self.flush_fields();
+        self.indent += 1;
+        self.blank = false;
+        self.start = true;
+    }
+
+    fn dedent(&mut self) {
+        self.flush_fields();
+        self.indent -= 1;
+        self.blank = false;
+    }
+
+    fn blank(&mut self) {
+        self.flush_fields();
+        self.blank = true;
+    }
+
+    fn doc(&mut self, doc: &str) {
+        for line in doc.lines().map(str::trim_end) {
+            if line.is_empty() {
+                self.line("//");
+            } else {
+                self.line(format!("// {}", line));
+            }
+        }
+    }
+
+    // Writes a struct field, made up of a name, type and tag. Anonymous fields
+    // and fields without a tag have fewer cells.
+    fn field(&mut self, cells: &[&str]) {
+        self.fields
+            .push(cells.iter().map(|cell| cell.to_string()).collect());
+    }
+
+    fn finish(mut self) -> String {
+        self.flush_fields();
+        self.out
+    }
+
+    fn write(&mut self, line: &str) {
+        if self.blank && !self.start {
+            self.out.push('\n');
+        }
+        self.blank = false;
+        self.start = false;
+
+        for _ in 0..self.indent {
+            self.out.push('\t');
+        }
+        self.out.push_str(line);
+        self.out.push('\n');
+    }
+
+    // This pads cells the way gofmt's tabwriter does: a cell followed by
+    // another is as wide as the widest such cell in its column, plus one, over
+    // the run of consecutive fields which have a cell after that column.
+    fn flush_fields(&mut self) {
+        let mut fields = std::mem::take(&mut self.fields);
+        let columns = fields.iter().map(Vec::len).max().unwrap_or(0);
+
+        for column in 0..columns.saturating_sub(1) {
+            let padded = |field: &Vec<String>| field.len() > column + 1;
+
+            let mut start = 0;
+            while start < fields.len() {
+                if !padded(&fields[start]) {
+                    start += 1;
+                    continue;
+                }
+
+                let end = (start..fields.len())
+                    .find(|&i| !padded(&fields[i]))
+                    .unwrap_or(fields.len());
+                let width = fields[start..end]
+                    .iter()
+                    .map(|field| field[column].chars().count())
+                    .max()
+                    .unwrap()
+                    + 1;
+
+                for field in &mut fields[start..end] {
+                    let pad = width - field[column].chars().count();
+                    field[column].push_str(&" ".repeat(pad));
+                }
+
+                start = end;
+            }
+        }
+
+        for field in fields {
+            self.write(&field.concat());
+        }
     }
 }
 
@@ -407,11 +2325,20 @@ pub enum Ast {
     Float64,
     String,
     Time,
+    // the wrapper generated for TimestampStyle::Wrapper
+    Timestamp,
+    // a type named in the schema's metadata, and the package it comes from
+    Custom(String, Option<String>),
     StrConstant(String),
     Const(String, String, Box<Ast>),
     Array(Box<Ast>),
     Map(Box<Ast>),
-    Struct(String, Vec<Property>),
+    Pointer(Box<Ast>),
+    // an optional property which may also be null
+    Nullable(Box<Ast>),
+    Struct(StructAst),
+    // the name of the enum, its schema path, and its members
+    Enum(String, String, Vec<EnumMember>),
     DiscriminatorStruct {
         // the name of the struct
         name: String,
@@ -421,28 +2348,75 @@ pub enum Ast {
         tag_short: String,
         // the name of the tag as it appears in JSON
         tag_json: String,
+        // the JSON Pointer to the discriminator's schema
+        schema_path: String,
+        // the mapping variants
+        variants: Vec<StructAst>,
+    },
+    DiscriminatorInterface {
+        // the name of the struct wrapping the interface
+        name: String,
+        // the name of the interface the variants implement
+        interface: String,
+        // the name of the tag as it appears in JSON
+        tag_json: String,
+        // the JSON Pointer to the discriminator's schema
+        schema_path: String,
         // the mapping variants
-        variants: Vec<DiscriminatorVariant>,
+        variants: Vec<StructAst>,
     },
     Identifier(String),
-    Typedef(String, Box<Ast>),
+    // a type defined in terms of another, rather than an alias of it
+    Defined(String, Box<Ast>),
+    // the name of the alias, its schema path, and the type it stands for
+    Typedef(String, String, Box<Ast>),
     Sequence(Vec<Ast>),
+    // the name of each file to write, and what goes in it
+    Files(Vec<(String, Ast)>),
+    // a doc comment, and the declaration it documents
+    Documented(String, Box<Ast>),
 }
 
 #[derive(Debug)]
-pub struct DiscriminatorVariant {
-    // the variant's name as it appears in Golang
+pub struct EnumMember {
+    // the name of the member's constant
     name: String,
-    // the variant's name as it appars in JSON
-    name_json: String,
-    // the properties of the variant
+    // the member's value as it appears in JSON
+    json: String,
+    description: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct StructAst {
+    // the struct's name as it appears in Golang
+    name: String,
+    description: Option<String>,
+    // the JSON Pointer to the struct's schema
+    schema_path: String,
+    // the tag's name and value as they appear in JSON, if this is a
+    // discriminator variant
+    tag: Option<(String, String)>,
+    // the properties of the struct
     properties: Vec<Property>,
+    // what happens to properties besides those
+    additional: Additional,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Additional {
+    // fail to decode an object with any other properties
+    Reject,
+    // decode an object with other properties, but discard them
+    Ignore,
+    // keep other properties in an Extra field
+    Capture,
 }
 
 #[derive(Debug)]
 pub struct Property {
     name: String,
-    required: bool,
     json: String,
+    required: bool,
+    description: Option<String>,
     ast: Ast,
 }
@@ -0,0 +1,534 @@
+use crate::ir::{DeclKind, Discriminator, Field, Ir, Struct, Type};
+use crate::target;
+use failure::Error;
+use inflector::Inflector;
+use std::collections::{BTreeSet, HashMap};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Options for generating Java.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Options {
+    /// The directory the generated Java is written into.
+    pub out_dir: PathBuf,
+
+    /// The Java package to generate classes into.
+    pub package: String,
+}
+
+impl Options {
+    /// Construct options for writing Java into `out_dir`, under the given
+    /// package.
+    pub fn new<P: Into<PathBuf>>(out_dir: P, package: &str) -> Options {
+        Options {
+            out_dir: out_dir.into(),
+            package: package.to_owned(),
+        }
+    }
+}
+
+pub struct Target {
+    out_path: PathBuf,
+    pkg_name: String,
+}
+
+impl target::Target for Target {
+    type Options = Options;
+    type Ast = Ast;
+
+    fn from_options(_root_name: &str, options: &Options) -> Result<Self, Error> {
+        // Java expects each package to live in a directory of its own,
+        // nested according to the components of the package name.
+        let out_path = options
+            .package
+            .split('.')
+            .fold(options.out_dir.clone(), |path, component| {
+                path.join(component)
+            });
+
+        Ok(Target {
+            out_path,
+            pkg_name: options.package.clone(),
+        })
+    }
+
+    fn transform(&self, ir: &Ir) -> Result<Ast, Error> {
+        let mut seq = vec![];
+
+        for decl in &ir.decls {
+            match &decl.kind {
+                DeclKind::Struct(Struct { fields, .. }) => {
+                    seq.push(Ast::Class(Class {
+                        name: decl.name.clone(),
+                        extends: None,
+                        properties: self.transform_fields(fields),
+                    }));
+                }
+                DeclKind::Enum(values) => {
+                    let members = values
+                        .iter()
+                        .map(|val| EnumMember {
+                            name: member_name(&val.json),
+                            json: val.json.to_owned(),
+                        })
+                        .collect();
+
+                    seq.push(Ast::Enum(decl.name.clone(), members));
+                }
+                DeclKind::Discriminator(Discriminator { tag, variants }) => {
+                    for variant in variants {
+                        seq.push(Ast::Class(Class {
+                            name: variant.name.clone(),
+                            extends: Some(decl.name.clone()),
+                            properties: self.transform_fields(&variant.body.fields),
+                        }));
+                    }
+
+                    seq.push(Ast::Discriminator {
+                        name: decl.name.clone(),
+                        tag_json: tag.to_owned(),
+                        variants: variants
+                            .iter()
+                            .map(|variant| (variant.tag_value.to_owned(), variant.name.clone()))
+                            .collect(),
+                    });
+                }
+                // Java has nothing an alias could be expanded into which would
+                // hold itself, so these are given a class of their own.
+                DeclKind::Alias(ty) if ir.is_recursive_alias(&decl.name) => {
+                    seq.push(Ast::Wrapper(
+                        decl.name.clone(),
+                        Box::new(self.transform_type(ty)),
+                    ));
+                }
+                DeclKind::Alias(ty) => {
+                    seq.push(Ast::Typedef(
+                        decl.name.clone(),
+                        Box::new(self.transform_type(ty)),
+                    ));
+                }
+            }
+        }
+
+        Ok(Ast::Sequence(seq))
+    }
+
+    fn serialize(&self, ast: &Ast) -> Result<Vec<(PathBuf, String)>, Error> {
+        let asts = match ast {
+            Ast::Sequence(asts) => asts,
+            _ => unreachable!("transform always returns a sequence"),
+        };
+
+        // Java has no type aliases, so a reference to a definition which isn't
+        // a class or enum is replaced by the definition's type itself.
+        let mut aliases = HashMap::new();
+        for ast in asts {
+            if let Ast::Typedef(name, ast) = ast {
+                aliases.insert(name.clone(), ast.as_ref());
+            }
+        }
+
+        // Every class or enum goes into a file of its own.
+        let mut files = Vec::new();
+        for ast in asts {
+            let name = match ast {
+                Ast::Class(Class { name, .. }) => name,
+                Ast::Enum(name, _) => name,
+                Ast::Discriminator { name, .. } => name,
+                Ast::Wrapper(name, _) => name,
+                _ => continue,
+            };
+
+            let path = self.out_path.join(format!("{}.java", name));
+            let mut out = Vec::new();
+
+            writeln!(out, "package {};", self.pkg_name)?;
+            writeln!(out)?;
+
+            let mut imports = BTreeSet::new();
+            self.imports(&mut imports, &aliases, ast);
+            for import in &imports {
+                writeln!(out, "import {};", import)?;
+            }
+            if !imports.is_empty() {
+                writeln!(out)?;
+            }
+
+            self.serialize_ast(&mut out, &aliases, ast)?;
+            files.push((path, String::from_utf8(out)?));
+        }
+
+        Ok(files)
+    }
+}
+
+impl Target {
+    fn transform_type(&self, ty: &Type) -> Ast {
+        match ty {
+            Type::Any => Ast::Object,
+            Type::Boolean => Ast::Boolean,
+            Type::String => Ast::String,
+            Type::Timestamp => Ast::OffsetDateTime,
+            Type::Int8 => Ast::Byte,
+            Type::Uint8 | Type::Int16 => Ast::Short,
+            Type::Uint16 | Type::Int32 => Ast::Integer,
+            Type::Uint32 => Ast::Long,
+            Type::Float32 => Ast::Float,
+            Type::Float64 => Ast::Double,
+            Type::Elements(ty) => Ast::List(Box::new(self.transform_type(ty))),
+            Type::Values(ty) => Ast::Map(Box::new(self.transform_type(ty))),
+            Type::Ref(name) => Ast::Identifier(name.clone()),
+            // Every type is already boxed, and so may already be null.
+            Type::Nullable(ty) => self.transform_type(ty),
+            Type::Custom(ty, _) => self.transform_type(ty),
+        }
+    }
+
+    fn transform_fields(&self, fields: &[Field]) -> Vec<Property> {
+        fields
+            .iter()
+            .map(|field| Property {
+                name: field_name(&field.json),
+                required: field.required,
+                json: field.json.to_owned(),
+                ast: self.transform_type(&field.ty),
+            })
+            .collect()
+    }
+
+    fn imports(&self, imports: &mut BTreeSet<&str>, aliases: &Aliases, ast: &Ast) {
+        match ast {
+            Ast::OffsetDateTime => {
+                imports.insert("java.time.OffsetDateTime");
+            }
+            Ast::List(ast) => {
+                imports.insert("java.util.List");
+                self.imports(imports, aliases, ast);
+            }
+            Ast::Map(ast) => {
+                imports.insert("java.util.Map");
+                self.imports(imports, aliases, ast);
+            }
+            Ast::Class(Class { properties, .. }) => {
+                for prop in properties {
+                    imports.insert("com.fasterxml.jackson.annotation.JsonProperty");
+                    if !prop.required {
+                        imports.insert("com.fasterxml.jackson.annotation.JsonInclude");
+                    }
+
+                    self.imports(imports, aliases, &prop.ast);
+                }
+            }
+            Ast::Enum(_, _) => {
+                imports.insert("com.fasterxml.jackson.annotation.JsonProperty");
+            }
+            Ast::Discriminator { .. } => {
+                imports.insert("com.fasterxml.jackson.annotation.JsonSubTypes");
+                imports.insert("com.fasterxml.jackson.annotation.JsonTypeInfo");
+            }
+            Ast::Wrapper(_, ast) => {
+                imports.insert("com.fasterxml.jackson.annotation.JsonCreator");
+                imports.insert("com.fasterxml.jackson.annotation.JsonValue");
+                self.imports(imports, aliases, ast);
+            }
+            Ast::Identifier(id) => {
+                if let Some(ast) = aliases.get(id) {
+                    self.imports(imports, aliases, ast);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn serialize_ast(&self, w: &mut dyn Write, aliases: &Aliases, ast: &Ast) -> Result<(), Error> {
+        match ast {
+            Ast::Object => write!(w, "Object")?,
+            Ast::Boolean => write!(w, "Boolean")?,
+            Ast::Byte => write!(w, "Byte")?,
+            Ast::Short => write!(w, "Short")?,
+            Ast::Integer => write!(w, "Integer")?,
+            Ast::Long => write!(w, "Long")?,
+            Ast::Float => write!(w, "Float")?,
+            Ast::Double => write!(w, "Double")?,
+            Ast::String => write!(w, "String")?,
+            Ast::OffsetDateTime => write!(w, "OffsetDateTime")?,
+            Ast::List(ast) => {
+                write!(w, "List<")?;
+                self.serialize_ast(w, aliases, ast)?;
+                write!(w, ">")?;
+            }
+            Ast::Map(ast) => {
+                write!(w, "Map<String, ")?;
+                self.serialize_ast(w, aliases, ast)?;
+                write!(w, ">")?;
+            }
+            Ast::Class(Class {
+                name,
+                extends,
+                properties,
+            }) => {
+                match extends {
+                    Some(base) => writeln!(w, "public class {} extends {} {{", name, base)?,
+                    None => writeln!(w, "public class {} {{", name)?,
+                }
+
+                for prop in properties {
+                    if !prop.required {
+                        writeln!(w, "    @JsonInclude(JsonInclude.Include.NON_NULL)")?;
+                    }
+                    writeln!(w, "    @JsonProperty({})", quote(&prop.json))?;
+                    write!(w, "    private ")?;
+                    self.serialize_ast(w, aliases, &prop.ast)?;
+                    writeln!(w, " {};", prop.name)?;
+                    writeln!(w)?;
+                }
+
+                writeln!(w, "    public {}() {{", name)?;
+                writeln!(w, "    }}")?;
+
+                for prop in properties {
+                    // Capitalizing only the first letter keeps any suffix
+                    // added to escape a keyword, so that a property named
+                    // "class" does not produce Object's getClass.
+                    let mut chars = prop.name.chars();
+                    let accessor: String = chars
+                        .next()
+                        .into_iter()
+                        .flat_map(char::to_uppercase)
+                        .chain(chars)
+                        .collect();
+
+                    writeln!(w)?;
+                    write!(w, "    public ")?;
+                    self.serialize_ast(w, aliases, &prop.ast)?;
+                    writeln!(w, " get{}() {{", accessor)?;
+                    writeln!(w, "        return {};", prop.name)?;
+                    writeln!(w, "    }}")?;
+
+                    writeln!(w)?;
+                    write!(w, "    public void set{}(", accessor)?;
+                    self.serialize_ast(w, aliases, &prop.ast)?;
+                    writeln!(w, " {}) {{", prop.name)?;
+                    writeln!(w, "        this.{} = {};", prop.name, prop.name)?;
+                    writeln!(w, "    }}")?;
+                }
+
+                writeln!(w, "}}")?;
+            }
+            Ast::Enum(name, members) => {
+                writeln!(w, "public enum {} {{", name)?;
+                for (i, member) in members.iter().enumerate() {
+                    if i != 0 {
+                        writeln!(w)?;
+                    }
+                    writeln!(w, "    @JsonProperty({})", quote(&member.json))?;
+                    writeln!(w, "    {},", member.name)?;
+                }
+                writeln!(w, "}}")?;
+            }
+            Ast::Discriminator {
+                name,
+                tag_json,
+                variants,
+            } => {
+                writeln!(
+                    w,
+                    "@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = {})",
+                    quote(tag_json)
+                )?;
+                writeln!(w, "@JsonSubTypes({{")?;
+                for (tag_value, variant) in variants {
+                    writeln!(
+                        w,
+                        "    @JsonSubTypes.Type(name = {}, value = {}.class),",
+                        quote(tag_value),
+                        variant
+                    )?;
+                }
+                writeln!(w, "}})")?;
+                writeln!(w, "public abstract class {} {{", name)?;
+                writeln!(w, "}}")?;
+            }
+            Ast::Wrapper(name, ast) => {
+                writeln!(w, "public class {} {{", name)?;
+                writeln!(w, "    @JsonValue")?;
+                write!(w, "    private ")?;
+                self.serialize_ast(w, aliases, ast)?;
+                writeln!(w, " value;")?;
+                writeln!(w)?;
+                writeln!(w, "    @JsonCreator(mode = JsonCreator.Mode.DELEGATING)")?;
+                write!(w, "    public {}(", name)?;
+                self.serialize_ast(w, aliases, ast)?;
+                writeln!(w, " value) {{")?;
+                writeln!(w, "        this.value = value;")?;
+                writeln!(w, "    }}")?;
+                writeln!(w)?;
+                write!(w, "    public ")?;
+                self.serialize_ast(w, aliases, ast)?;
+                writeln!(w, " getValue() {{")?;
+                writeln!(w, "        return value;")?;
+                writeln!(w, "    }}")?;
+                writeln!(w)?;
+                write!(w, "    public void setValue(")?;
+                self.serialize_ast(w, aliases, ast)?;
+                writeln!(w, " value) {{")?;
+                writeln!(w, "        this.value = value;")?;
+                writeln!(w, "    }}")?;
+                writeln!(w, "}}")?;
+            }
+            Ast::Identifier(id) => match aliases.get(id) {
+                Some(ast) => self.serialize_ast(w, aliases, ast)?,
+                None => write!(w, "{}", id)?,
+            },
+            // Aliases are never written out themselves; see `serialize`.
+            Ast::Typedef(_, _) | Ast::Sequence(_) => {}
+        }
+
+        Ok(())
+    }
+}
+
+// The aliases which are expanded wherever they are referred to. None of these
+// refer back to themselves, as those are wrapped instead.
+type Aliases<'a> = HashMap<String, &'a Ast>;
+
+const KEYWORDS: &[&str] = &[
+    "_",
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "while",
+];
+
+fn field_name(json: &str) -> String {
+    let name = json.to_camel_case();
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else if !name.starts_with(char::is_alphabetic) {
+        format!("x{}", name)
+    } else {
+        name
+    }
+}
+
+fn member_name(json: &str) -> String {
+    let name = json.to_screaming_snake_case();
+    if name.starts_with(char::is_alphabetic) {
+        name
+    } else {
+        format!("X{}", name)
+    }
+}
+
+/// A Java string literal with the given contents.
+fn quote(s: &str) -> String {
+    // JSON string escapes are a subset of Java's.
+    serde_json::to_string(s).unwrap()
+}
+
+#[derive(Debug)]
+pub enum Ast {
+    Object,
+    Boolean,
+    Byte,
+    Short,
+    Integer,
+    Long,
+    Float,
+    Double,
+    String,
+    OffsetDateTime,
+    List(Box<Ast>),
+    Map(Box<Ast>),
+    Class(Class),
+    Enum(String, Vec<EnumMember>),
+    Discriminator {
+        // the name of the abstract base class
+        name: String,
+        // the name of the tag as it appears in JSON
+        tag_json: String,
+        // the tag values, and the name of the subclass each one maps to
+        variants: Vec<(String, String)>,
+    },
+    Identifier(String),
+    Typedef(String, Box<Ast>),
+    // a class holding a single value, which is read and written as that value
+    Wrapper(String, Box<Ast>),
+    Sequence(Vec<Ast>),
+}
+
+#[derive(Debug)]
+pub struct Class {
+    // the name of the class
+    name: String,
+    // the discriminator base class, if this is a discriminator variant
+    extends: Option<String>,
+    // the properties of the class
+    properties: Vec<Property>,
+}
+
+#[derive(Debug)]
+pub struct EnumMember {
+    // the member's name as it appears in Java
+    name: String,
+    // the member's value as it appears in JSON
+    json: String,
+}
+
+#[derive(Debug)]
+pub struct Property {
+    name: String,
+    required: bool,
+    json: String,
+    ast: Ast,
+}
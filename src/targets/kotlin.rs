@@ -0,0 +1,406 @@
+use crate::ir::{DeclKind, Discriminator, Field, Ir, Struct, Type};
+use crate::target;
+use failure::Error;
+use inflector::Inflector;
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Options for generating Kotlin.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Options {
+    /// The directory the generated Kotlin is written into.
+    pub out_dir: PathBuf,
+
+    /// The Kotlin package to generate classes into.
+    pub package: String,
+}
+
+impl Options {
+    /// Construct options for writing Kotlin into `out_dir`, under the given
+    /// package.
+    pub fn new<P: Into<PathBuf>>(out_dir: P, package: &str) -> Options {
+        Options {
+            out_dir: out_dir.into(),
+            package: package.to_owned(),
+        }
+    }
+}
+
+pub struct Target {
+    out_path: PathBuf,
+    pkg_name: String,
+}
+
+impl target::Target for Target {
+    type Options = Options;
+    type Ast = Ast;
+
+    fn from_options(root_name: &str, options: &Options) -> Result<Self, Error> {
+        Ok(Target {
+            out_path: options
+                .out_dir
+                .join(format!("{}.kt", root_name.to_pascal_case())),
+            pkg_name: options.package.clone(),
+        })
+    }
+
+    fn transform(&self, ir: &Ir) -> Result<Ast, Error> {
+        let mut seq = vec![];
+
+        for decl in &ir.decls {
+            match &decl.kind {
+                DeclKind::Struct(Struct { fields, .. }) => {
+                    seq.push(Ast::DataClass(DataClass {
+                        name: decl.name.clone(),
+                        parent: None,
+                        properties: self.transform_fields(fields),
+                    }));
+                }
+                DeclKind::Enum(values) => {
+                    let members = values
+                        .iter()
+                        .map(|val| EnumMember {
+                            name: member_name(&val.json),
+                            json: val.json.to_owned(),
+                        })
+                        .collect();
+
+                    seq.push(Ast::EnumClass(decl.name.clone(), members));
+                }
+                DeclKind::Discriminator(Discriminator { tag, variants }) => {
+                    for variant in variants {
+                        seq.push(Ast::DataClass(DataClass {
+                            name: variant.name.clone(),
+                            parent: Some((decl.name.clone(), variant.tag_value.to_owned())),
+                            properties: self.transform_fields(&variant.body.fields),
+                        }));
+                    }
+
+                    seq.push(Ast::SealedClass(decl.name.clone(), tag.to_owned()));
+                }
+                // A typealias cannot refer back to itself, so these become a
+                // value class instead, which is serialized as the value it
+                // wraps.
+                DeclKind::Alias(ty) if ir.is_recursive_alias(&decl.name) => {
+                    seq.push(Ast::ValueClass(
+                        decl.name.clone(),
+                        Box::new(self.transform_type(ty)),
+                    ));
+                }
+                DeclKind::Alias(ty) => {
+                    seq.push(Ast::Typealias(
+                        decl.name.clone(),
+                        Box::new(self.transform_type(ty)),
+                    ));
+                }
+            }
+        }
+
+        Ok(Ast::Sequence(seq))
+    }
+
+    fn serialize(&self, ast: &Ast) -> Result<Vec<(PathBuf, String)>, Error> {
+        let mut out = Vec::new();
+
+        writeln!(out, "package {}", self.pkg_name)?;
+        writeln!(out)?;
+
+        let mut imports = BTreeSet::new();
+        self.imports(&mut imports, ast);
+        for import in &imports {
+            writeln!(out, "import {}", import)?;
+        }
+        if !imports.is_empty() {
+            writeln!(out)?;
+        }
+
+        self.serialize_ast(&mut out, ast)?;
+
+        Ok(vec![(self.out_path.clone(), String::from_utf8(out)?)])
+    }
+}
+
+impl Target {
+    fn transform_type(&self, ty: &Type) -> Ast {
+        match ty {
+            Type::Any => Ast::JsonElement,
+            Type::Boolean => Ast::Boolean,
+            Type::String | Type::Timestamp => Ast::String,
+            Type::Int8 => Ast::Byte,
+            Type::Uint8 | Type::Int16 => Ast::Short,
+            Type::Uint16 | Type::Int32 => Ast::Int,
+            Type::Uint32 => Ast::Long,
+            Type::Float32 => Ast::Float,
+            Type::Float64 => Ast::Double,
+            Type::Elements(ty) => Ast::List(Box::new(self.transform_type(ty))),
+            Type::Values(ty) => Ast::Map(Box::new(self.transform_type(ty))),
+            Type::Ref(name) => Ast::Identifier(name.clone()),
+            Type::Nullable(ty) => Ast::Nullable(Box::new(self.transform_type(ty))),
+            Type::Custom(ty, _) => self.transform_type(ty),
+        }
+    }
+
+    fn transform_fields(&self, fields: &[Field]) -> Vec<Property> {
+        fields
+            .iter()
+            .map(|field| Property {
+                name: field_name(&field.json),
+                required: field.required,
+                json: field.json.to_owned(),
+                ast: self.transform_type(&field.ty),
+            })
+            .collect()
+    }
+
+    fn imports(&self, imports: &mut BTreeSet<&str>, ast: &Ast) {
+        match ast {
+            Ast::JsonElement => {
+                imports.insert("kotlinx.serialization.json.JsonElement");
+            }
+            Ast::List(ast) | Ast::Map(ast) | Ast::Nullable(ast) | Ast::Typealias(_, ast) => {
+                self.imports(imports, ast)
+            }
+            Ast::DataClass(DataClass {
+                parent, properties, ..
+            }) => {
+                imports.insert("kotlinx.serialization.Serializable");
+                if parent.is_some() || !properties.is_empty() {
+                    imports.insert("kotlinx.serialization.SerialName");
+                }
+
+                for prop in properties {
+                    self.imports(imports, &prop.ast);
+                }
+            }
+            Ast::ValueClass(_, ast) => {
+                imports.insert("kotlinx.serialization.Serializable");
+                self.imports(imports, ast);
+            }
+            Ast::EnumClass(_, _) => {
+                imports.insert("kotlinx.serialization.SerialName");
+                imports.insert("kotlinx.serialization.Serializable");
+            }
+            Ast::SealedClass(_, _) => {
+                imports.insert("kotlinx.serialization.ExperimentalSerializationApi");
+                imports.insert("kotlinx.serialization.Serializable");
+                imports.insert("kotlinx.serialization.json.JsonClassDiscriminator");
+            }
+            Ast::Sequence(asts) => {
+                for ast in asts {
+                    self.imports(imports, ast);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn serialize_ast(&self, w: &mut dyn Write, ast: &Ast) -> Result<(), Error> {
+        match ast {
+            Ast::JsonElement => write!(w, "JsonElement")?,
+            Ast::Boolean => write!(w, "Boolean")?,
+            Ast::Byte => write!(w, "Byte")?,
+            Ast::Short => write!(w, "Short")?,
+            Ast::Int => write!(w, "Int")?,
+            Ast::Long => write!(w, "Long")?,
+            Ast::Float => write!(w, "Float")?,
+            Ast::Double => write!(w, "Double")?,
+            Ast::String => write!(w, "String")?,
+            Ast::List(ast) => {
+                write!(w, "List<")?;
+                self.serialize_ast(w, ast)?;
+                write!(w, ">")?;
+            }
+            Ast::Map(ast) => {
+                write!(w, "Map<String, ")?;
+                self.serialize_ast(w, ast)?;
+                write!(w, ">")?;
+            }
+            Ast::Nullable(ast) => {
+                self.serialize_ast(w, ast)?;
+                write!(w, "?")?;
+            }
+            Ast::DataClass(DataClass {
+                name,
+                parent,
+                properties,
+            }) => {
+                writeln!(w, "@Serializable")?;
+                if let Some((_, tag_value)) = parent {
+                    writeln!(w, "@SerialName({})", quote(tag_value))?;
+                }
+
+                // Kotlin does not allow data classes without any properties.
+                if properties.is_empty() {
+                    write!(w, "class {}", name)?;
+                } else {
+                    writeln!(w, "data class {}(", name)?;
+                    for prop in properties {
+                        writeln!(w, "    @SerialName({})", quote(&prop.json))?;
+                        write!(w, "    val {}: ", prop.name)?;
+                        self.serialize_ast(w, &prop.ast)?;
+                        if prop.required {
+                            writeln!(w, ",")?;
+                        } else if let Ast::Nullable(_) = prop.ast {
+                            writeln!(w, " = null,")?;
+                        } else {
+                            writeln!(w, "? = null,")?;
+                        }
+                    }
+                    write!(w, ")")?;
+                }
+
+                if let Some((parent, _)) = parent {
+                    write!(w, " : {}()", parent)?;
+                }
+                writeln!(w)?;
+            }
+            Ast::EnumClass(name, members) => {
+                writeln!(w, "@Serializable")?;
+                writeln!(w, "enum class {} {{", name)?;
+                for member in members {
+                    writeln!(w, "    @SerialName({})", quote(&member.json))?;
+                    writeln!(w, "    {},", member.name)?;
+                }
+                writeln!(w, "}}")?;
+            }
+            Ast::SealedClass(name, tag_json) => {
+                writeln!(w, "@OptIn(ExperimentalSerializationApi::class)")?;
+                writeln!(w, "@Serializable")?;
+                writeln!(w, "@JsonClassDiscriminator({})", quote(tag_json))?;
+                writeln!(w, "sealed class {}", name)?;
+            }
+            Ast::Identifier(id) => write!(w, "{}", id)?,
+            Ast::ValueClass(name, ast) => {
+                writeln!(w, "@Serializable")?;
+                writeln!(w, "@JvmInline")?;
+                write!(w, "value class {}(val value: ", name)?;
+                self.serialize_ast(w, ast)?;
+                writeln!(w, ")")?;
+            }
+            Ast::Typealias(name, ast) => {
+                write!(w, "typealias {} = ", name)?;
+                self.serialize_ast(w, ast)?;
+                writeln!(w)?;
+            }
+            Ast::Sequence(asts) => {
+                for ast in asts {
+                    self.serialize_ast(w, ast)?;
+                    writeln!(w)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+fn field_name(json: &str) -> String {
+    let name = json.to_camel_case();
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("`{}`", name)
+    } else if !name.starts_with(char::is_alphabetic) {
+        format!("x{}", name)
+    } else {
+        name
+    }
+}
+
+fn member_name(json: &str) -> String {
+    let name = json.to_screaming_snake_case();
+    if name.starts_with(char::is_alphabetic) {
+        name
+    } else {
+        format!("X{}", name)
+    }
+}
+
+/// A Kotlin string literal with the given contents.
+fn quote(s: &str) -> String {
+    // JSON string escapes are a subset of Kotlin's, but "$" would otherwise
+    // start a string template.
+    serde_json::to_string(s).unwrap().replace('$', "\\$")
+}
+
+#[derive(Debug)]
+pub enum Ast {
+    JsonElement,
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    List(Box<Ast>),
+    Map(Box<Ast>),
+    Nullable(Box<Ast>),
+    DataClass(DataClass),
+    EnumClass(String, Vec<EnumMember>),
+    // the name of the sealed class, and the name of its tag as it appears in
+    // JSON
+    SealedClass(String, String),
+    Identifier(String),
+    ValueClass(String, Box<Ast>),
+    Typealias(String, Box<Ast>),
+    Sequence(Vec<Ast>),
+}
+
+#[derive(Debug)]
+pub struct DataClass {
+    // the name of the class
+    name: String,
+    // the sealed class and tag value, if this is a discriminator variant
+    parent: Option<(String, String)>,
+    // the properties of the class
+    properties: Vec<Property>,
+}
+
+#[derive(Debug)]
+pub struct EnumMember {
+    // the member's name as it appears in Kotlin
+    name: String,
+    // the member's value as it appears in JSON
+    json: String,
+}
+
+#[derive(Debug)]
+pub struct Property {
+    name: String,
+    required: bool,
+    json: String,
+    ast: Ast,
+}
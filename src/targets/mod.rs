@@ -1,2 +1,8 @@
+pub mod csharp;
 pub mod golang;
+pub mod java;
+pub mod kotlin;
+pub mod python;
+pub mod rust;
+pub mod swift;
 pub mod typescript;
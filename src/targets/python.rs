@@ -0,0 +1,643 @@
+use crate::ir::{DeclKind, Discriminator, Field, Ir, Struct, Type};
+use crate::target;
+use failure::Error;
+use inflector::Inflector;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Options for generating Python.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Options {
+    /// The directory the generated Python is written into.
+    pub out_dir: PathBuf,
+}
+
+impl Options {
+    /// Construct options for writing Python into `out_dir`.
+    pub fn new<P: Into<PathBuf>>(out_dir: P) -> Options {
+        Options {
+            out_dir: out_dir.into(),
+        }
+    }
+}
+
+pub struct Target {
+    out_path: PathBuf,
+}
+
+impl target::Target for Target {
+    type Options = Options;
+    type Ast = Ast;
+
+    fn from_options(_root_name: &str, options: &Options) -> Result<Self, Error> {
+        Ok(Target {
+            out_path: options.out_dir.join("__init__.py"),
+        })
+    }
+
+    fn transform(&self, ir: &Ir) -> Result<Ast, Error> {
+        let mut seq = vec![];
+
+        for decl in &ir.decls {
+            match &decl.kind {
+                DeclKind::Struct(Struct { fields, .. }) => {
+                    seq.push(Ast::Dataclass(Dataclass {
+                        name: decl.name.clone(),
+                        base: None,
+                        tag: None,
+                        properties: self.transform_fields(fields),
+                    }));
+                }
+                DeclKind::Enum(values) => {
+                    let members = values
+                        .iter()
+                        .map(|val| EnumMember {
+                            name: member_name(&val.json),
+                            json: val.json.to_owned(),
+                        })
+                        .collect();
+
+                    seq.push(Ast::Enum(decl.name.clone(), members));
+                }
+                DeclKind::Discriminator(Discriminator { tag, variants }) => {
+                    // The variants subclass the discriminator's base class, so
+                    // the base class has to be declared before any of them.
+                    seq.push(Ast::Discriminator {
+                        name: decl.name.clone(),
+                        tag_json: tag.to_owned(),
+                        variants: variants
+                            .iter()
+                            .map(|variant| (variant.tag_value.to_owned(), variant.name.clone()))
+                            .collect(),
+                    });
+
+                    for variant in variants {
+                        seq.push(Ast::Dataclass(Dataclass {
+                            name: variant.name.clone(),
+                            base: Some(decl.name.clone()),
+                            tag: Some((tag.to_owned(), variant.tag_value.to_owned())),
+                            properties: self.transform_fields(&variant.body.fields),
+                        }));
+                    }
+                }
+                DeclKind::Alias(ty) => {
+                    seq.push(Ast::Typedef(
+                        decl.name.clone(),
+                        Box::new(self.transform_type(ty)),
+                    ));
+                }
+            }
+        }
+
+        Ok(Ast::Sequence(seq))
+    }
+
+    fn serialize(&self, ast: &Ast) -> Result<Vec<(PathBuf, String)>, Error> {
+        let mut out = Vec::new();
+
+        writeln!(out, "from __future__ import annotations")?;
+        writeln!(out)?;
+        writeln!(out, "from dataclasses import dataclass")?;
+        writeln!(out, "from datetime import datetime")?;
+        writeln!(out, "from enum import Enum")?;
+        writeln!(out, "from typing import Any, Dict, List, Optional")?;
+        writeln!(out)?;
+        writeln!(out)?;
+        writeln!(out, "def _parse_timestamp(value: str) -> datetime:")?;
+        writeln!(
+            out,
+            "    # datetime.fromisoformat does not understand the \"Z\" suffix."
+        )?;
+        writeln!(
+            out,
+            "    return datetime.fromisoformat(value.replace(\"Z\", \"+00:00\"))"
+        )?;
+        writeln!(out)?;
+        writeln!(out)?;
+
+        // Type aliases have no from_json or to_json of their own, so converting
+        // to or from one means converting the aliased type instead.
+        let mut typedefs = HashMap::new();
+        if let Ast::Sequence(asts) = ast {
+            for ast in asts {
+                if let Ast::Typedef(name, ast) = ast {
+                    typedefs.insert(name.as_str(), ast.as_ref());
+                }
+            }
+        }
+
+        // An alias which refers back to itself can only be made up of lists,
+        // dicts and optionals of itself, so there is nothing in it to convert.
+        // These are left as None, rather than expanded forever.
+        let aliases: Aliases = typedefs
+            .iter()
+            .map(|(&name, &ast)| {
+                let ast = if alias_refers_to(&typedefs, ast, name) {
+                    None
+                } else {
+                    Some(ast)
+                };
+
+                (name.to_owned(), ast)
+            })
+            .collect();
+
+        match ast {
+            Ast::Sequence(asts) => {
+                // Aliases are assignments, evaluated when the module is
+                // imported, so they come after the classes they may name, and
+                // after any alias they name.
+                for ast in asts {
+                    if let Ast::Typedef(..) = ast {
+                        continue;
+                    }
+
+                    self.serialize_ast(&mut out, &aliases, ast)?;
+                    writeln!(out)?;
+                }
+
+                let mut defined = HashSet::new();
+                for ast in asts {
+                    if let Ast::Typedef(name, _) = ast {
+                        self.serialize_typedef(&mut out, &typedefs, &mut defined, name)?;
+                    }
+                }
+            }
+            _ => self.serialize_ast(&mut out, &aliases, ast)?,
+        }
+
+        Ok(vec![(self.out_path.clone(), String::from_utf8(out)?)])
+    }
+}
+
+impl Target {
+    fn transform_type(&self, ty: &Type) -> Ast {
+        match ty {
+            Type::Any => Ast::Any,
+            Type::Boolean => Ast::Bool,
+            Type::String => Ast::Str,
+            Type::Timestamp => Ast::Datetime,
+            Type::Int8 | Type::Uint8 | Type::Int16 | Type::Uint16 | Type::Int32 | Type::Uint32 => {
+                Ast::Int
+            }
+            Type::Float32 | Type::Float64 => Ast::Float,
+            Type::Elements(ty) => Ast::List(Box::new(self.transform_type(ty))),
+            Type::Values(ty) => Ast::Dict(Box::new(self.transform_type(ty))),
+            Type::Ref(name) => Ast::Identifier(name.clone()),
+            Type::Nullable(ty) => Ast::Optional(Box::new(self.transform_type(ty))),
+            Type::Custom(ty, _) => self.transform_type(ty),
+        }
+    }
+
+    fn transform_fields(&self, fields: &[Field]) -> Vec<Property> {
+        fields
+            .iter()
+            .map(|field| Property {
+                name: field_name(&field.json),
+                required: field.required,
+                json: field.json.to_owned(),
+                ast: self.transform_type(&field.ty),
+            })
+            .collect()
+    }
+
+    fn serialize_ast(&self, w: &mut dyn Write, aliases: &Aliases, ast: &Ast) -> Result<(), Error> {
+        match ast {
+            Ast::Any => write!(w, "Any")?,
+            Ast::Bool => write!(w, "bool")?,
+            Ast::Int => write!(w, "int")?,
+            Ast::Float => write!(w, "float")?,
+            Ast::Str => write!(w, "str")?,
+            Ast::Datetime => write!(w, "datetime")?,
+            Ast::List(ast) => {
+                write!(w, "List[")?;
+                self.serialize_ast(w, aliases, ast)?;
+                write!(w, "]")?;
+            }
+            Ast::Dict(ast) => {
+                write!(w, "Dict[str, ")?;
+                self.serialize_ast(w, aliases, ast)?;
+                write!(w, "]")?;
+            }
+            Ast::Optional(ast) => {
+                write!(w, "Optional[")?;
+                self.serialize_ast(w, aliases, ast)?;
+                write!(w, "]")?;
+            }
+            Ast::Dataclass(Dataclass {
+                name,
+                base,
+                tag,
+                properties,
+            }) => {
+                writeln!(w, "@dataclass")?;
+                match base {
+                    Some(base) => writeln!(w, "class {}({}):", name, base)?,
+                    None => writeln!(w, "class {}:", name)?,
+                }
+
+                // Optional properties have a default, and so must come after
+                // all of the required ones.
+                let props: Vec<_> = properties
+                    .iter()
+                    .filter(|p| p.required)
+                    .chain(properties.iter().filter(|p| !p.required))
+                    .collect();
+
+                for prop in &props {
+                    write!(w, "    {}: ", prop.name)?;
+                    if prop.required {
+                        self.serialize_ast(w, aliases, &prop.ast)?;
+                        writeln!(w)?;
+                    } else if let Ast::Optional(_) = prop.ast {
+                        // Python has no way to tell an absent property from a
+                        // null one; both are None.
+                        self.serialize_ast(w, aliases, &prop.ast)?;
+                        writeln!(w, " = None")?;
+                    } else {
+                        write!(w, "Optional[")?;
+                        self.serialize_ast(w, aliases, &prop.ast)?;
+                        writeln!(w, "] = None")?;
+                    }
+                }
+
+                if !props.is_empty() {
+                    writeln!(w)?;
+                }
+
+                writeln!(w, "    @classmethod")?;
+                writeln!(
+                    w,
+                    "    def from_json(cls, data: Dict[str, Any]) -> {}:",
+                    name
+                )?;
+                writeln!(w, "        return cls(")?;
+                for prop in &props {
+                    let value = format!("data[{}]", quote(&prop.json));
+                    let value = self.decode_expr(aliases, prop.value_ast(), &value, 0);
+                    if prop.required {
+                        writeln!(w, "            {}={},", prop.name, value)?;
+                    } else {
+                        writeln!(
+                            w,
+                            "            {}=None if data.get({}) is None else {},",
+                            prop.name,
+                            quote(&prop.json),
+                            value
+                        )?;
+                    }
+                }
+                writeln!(w, "        )")?;
+                writeln!(w)?;
+
+                writeln!(w, "    def to_json(self) -> Dict[str, Any]:")?;
+                writeln!(w, "        data: Dict[str, Any] = {{}}")?;
+                if let Some((tag_json, tag_value)) = tag {
+                    writeln!(
+                        w,
+                        "        data[{}] = {}",
+                        quote(tag_json),
+                        quote(tag_value)
+                    )?;
+                }
+                for prop in &props {
+                    let value = format!("self.{}", prop.name);
+                    let json_value = self.encode_expr(aliases, prop.value_ast(), &value, 0);
+                    if prop.required {
+                        writeln!(w, "        data[{}] = {}", quote(&prop.json), json_value)?;
+                    } else {
+                        writeln!(w, "        if {} is not None:", value)?;
+                        writeln!(
+                            w,
+                            "            data[{}] = {}",
+                            quote(&prop.json),
+                            json_value
+                        )?;
+                    }
+                }
+                writeln!(w, "        return data")?;
+                writeln!(w)?;
+            }
+            Ast::Enum(name, members) => {
+                writeln!(w, "class {}(Enum):", name)?;
+                for member in members {
+                    writeln!(w, "    {} = {}", member.name, quote(&member.json))?;
+                }
+                writeln!(w)?;
+                writeln!(w, "    @classmethod")?;
+                writeln!(w, "    def from_json(cls, data: str) -> {}:", name)?;
+                writeln!(w, "        return cls(data)")?;
+                writeln!(w)?;
+                writeln!(w, "    def to_json(self) -> str:")?;
+                writeln!(w, "        return self.value")?;
+                writeln!(w)?;
+            }
+            Ast::Discriminator {
+                name,
+                tag_json,
+                variants,
+            } => {
+                writeln!(w, "class {}:", name)?;
+                writeln!(w, "    @classmethod")?;
+                writeln!(
+                    w,
+                    "    def from_json(cls, data: Dict[str, Any]) -> {}:",
+                    name
+                )?;
+                writeln!(w, "        tag = data[{}]", quote(tag_json))?;
+                for (tag_value, variant) in variants {
+                    writeln!(w, "        if tag == {}:", quote(tag_value))?;
+                    writeln!(w, "            return {}.from_json(data)", variant)?;
+                }
+                writeln!(
+                    w,
+                    "        raise ValueError(\"unknown discriminator tag value: {{!r}}\".format(tag))"
+                )?;
+                writeln!(w)?;
+                writeln!(w, "    def to_json(self) -> Dict[str, Any]:")?;
+                writeln!(w, "        raise NotImplementedError")?;
+                writeln!(w)?;
+            }
+            Ast::Identifier(id) => write!(w, "{}", id)?,
+            Ast::Typedef(name, ast) => {
+                write!(w, "{} = ", name)?;
+                self.serialize_ast(w, aliases, ast)?;
+                writeln!(w)?;
+            }
+            Ast::Sequence(asts) => {
+                for ast in asts {
+                    self.serialize_ast(w, aliases, ast)?;
+                    writeln!(w)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Writes out an alias, after any other alias it names. An alias which
+    // refers back to itself cannot be named until it has been defined, so it is
+    // named by a string instead, which typing takes as a forward reference.
+    fn serialize_typedef<'a>(
+        &self,
+        w: &mut dyn Write,
+        typedefs: &HashMap<&'a str, &'a Ast>,
+        defined: &mut HashSet<&'a str>,
+        name: &'a str,
+    ) -> Result<(), Error> {
+        if !defined.insert(name) {
+            return Ok(());
+        }
+
+        let ast = typedefs[name];
+        if let Some(target) = alias_target(ast) {
+            if let Some((&target, _)) = typedefs.get_key_value(target) {
+                self.serialize_typedef(w, typedefs, defined, target)?;
+            }
+        }
+
+        write!(w, "{} = ", name)?;
+        self.serialize_alias_type(w, typedefs, ast)?;
+        writeln!(w)?;
+        writeln!(w)?;
+
+        Ok(())
+    }
+
+    fn serialize_alias_type(
+        &self,
+        w: &mut dyn Write,
+        typedefs: &HashMap<&str, &Ast>,
+        ast: &Ast,
+    ) -> Result<(), Error> {
+        let (open, ast) = match ast {
+            Ast::List(ast) => ("List[", ast),
+            Ast::Dict(ast) => ("Dict[str, ", ast),
+            Ast::Optional(ast) => ("Optional[", ast),
+            Ast::Identifier(id) if typedefs.contains_key(id.as_str()) => {
+                if alias_refers_to(typedefs, typedefs[id.as_str()], id) {
+                    write!(w, "{}", quote(id))?;
+                } else {
+                    write!(w, "{}", id)?;
+                }
+                return Ok(());
+            }
+            _ => return self.serialize_ast(w, &HashMap::new(), ast),
+        };
+
+        write!(w, "{}", open)?;
+        self.serialize_alias_type(w, typedefs, ast)?;
+        write!(w, "]")?;
+        Ok(())
+    }
+
+    fn decode_expr(&self, aliases: &Aliases, ast: &Ast, value: &str, depth: usize) -> String {
+        match ast {
+            Ast::Datetime => format!("_parse_timestamp({})", value),
+            Ast::Optional(ast) => {
+                let inner = self.decode_expr(aliases, ast, value, depth);
+                if inner == value {
+                    inner
+                } else {
+                    format!("None if {} is None else {}", value, inner)
+                }
+            }
+            Ast::List(ast) => {
+                let v = format!("v{}", depth);
+                format!(
+                    "[{} for {} in {}]",
+                    self.decode_expr(aliases, ast, &v, depth + 1),
+                    v,
+                    value
+                )
+            }
+            Ast::Dict(ast) => {
+                let (k, v) = (format!("k{}", depth), format!("v{}", depth));
+                format!(
+                    "{{{}: {} for {}, {} in {}.items()}}",
+                    k,
+                    self.decode_expr(aliases, ast, &v, depth + 1),
+                    k,
+                    v,
+                    value
+                )
+            }
+            Ast::Identifier(id) => match aliases.get(id) {
+                Some(Some(ast)) => self.decode_expr(aliases, ast, value, depth),
+                Some(None) => value.to_owned(),
+                None => format!("{}.from_json({})", id, value),
+            },
+            _ => value.to_owned(),
+        }
+    }
+
+    fn encode_expr(&self, aliases: &Aliases, ast: &Ast, value: &str, depth: usize) -> String {
+        match ast {
+            Ast::Datetime => format!("{}.isoformat()", value),
+            Ast::Optional(ast) => {
+                let inner = self.encode_expr(aliases, ast, value, depth);
+                if inner == value {
+                    inner
+                } else {
+                    format!("None if {} is None else {}", value, inner)
+                }
+            }
+            Ast::List(ast) => {
+                let v = format!("v{}", depth);
+                format!(
+                    "[{} for {} in {}]",
+                    self.encode_expr(aliases, ast, &v, depth + 1),
+                    v,
+                    value
+                )
+            }
+            Ast::Dict(ast) => {
+                let (k, v) = (format!("k{}", depth), format!("v{}", depth));
+                format!(
+                    "{{{}: {} for {}, {} in {}.items()}}",
+                    k,
+                    self.encode_expr(aliases, ast, &v, depth + 1),
+                    k,
+                    v,
+                    value
+                )
+            }
+            Ast::Identifier(id) => match aliases.get(id) {
+                Some(Some(ast)) => self.encode_expr(aliases, ast, value, depth),
+                Some(None) => value.to_owned(),
+                None => format!("{}.to_json()", value),
+            },
+            _ => value.to_owned(),
+        }
+    }
+}
+
+// How to convert to and from each alias: by way of the type it aliases, or not
+// at all, for one which refers back to itself.
+type Aliases<'a> = HashMap<String, Option<&'a Ast>>;
+
+// The name at the end of an alias's lists, dicts and optionals, if any.
+fn alias_target(ast: &Ast) -> Option<&str> {
+    match ast {
+        Ast::List(ast) | Ast::Dict(ast) | Ast::Optional(ast) => alias_target(ast),
+        Ast::Identifier(id) => Some(id),
+        _ => None,
+    }
+}
+
+// Whether following an alias, and any alias it names in turn, leads to name.
+fn alias_refers_to(typedefs: &HashMap<&str, &Ast>, ast: &Ast, name: &str) -> bool {
+    let mut seen = HashSet::new();
+    let mut ast = ast;
+    while let Some(target) = alias_target(ast) {
+        if target == name {
+            return true;
+        }
+        match typedefs.get(target) {
+            Some(next) if seen.insert(target) => ast = next,
+            _ => return false,
+        }
+    }
+
+    false
+}
+
+const KEYWORDS: &[&str] = &[
+    "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif",
+    "else", "except", "finally", "for", "from", "global", "if", "import", "in", "is", "lambda",
+    "nonlocal", "not", "or", "pass", "raise", "return", "try", "while", "with", "yield",
+];
+
+fn field_name(json: &str) -> String {
+    let name = json.to_snake_case();
+    match name.as_ref() {
+        // These would clash with the methods generated on every dataclass, or
+        // with their arguments.
+        "from_json" | "to_json" | "self" => format!("{}_", name),
+        _ if KEYWORDS.contains(&name.as_ref()) => format!("{}_", name),
+        _ if !name.starts_with(char::is_alphabetic) => format!("x{}", name),
+        _ => name,
+    }
+}
+
+fn member_name(json: &str) -> String {
+    let name = json.to_screaming_snake_case();
+    if name.starts_with(char::is_alphabetic) {
+        name
+    } else {
+        format!("X{}", name)
+    }
+}
+
+/// A Python string literal with the given contents.
+fn quote(s: &str) -> String {
+    // JSON string escapes are a subset of Python's.
+    serde_json::to_string(s).unwrap()
+}
+
+#[derive(Debug)]
+pub enum Ast {
+    Any,
+    Bool,
+    Int,
+    Float,
+    Str,
+    Datetime,
+    List(Box<Ast>),
+    Dict(Box<Ast>),
+    Optional(Box<Ast>),
+    Dataclass(Dataclass),
+    Enum(String, Vec<EnumMember>),
+    Discriminator {
+        // the name of the base class
+        name: String,
+        // the name of the tag as it appears in JSON
+        tag_json: String,
+        // the tag values, and the name of the subclass each one maps to
+        variants: Vec<(String, String)>,
+    },
+    Identifier(String),
+    Typedef(String, Box<Ast>),
+    Sequence(Vec<Ast>),
+}
+
+#[derive(Debug)]
+pub struct Dataclass {
+    // the name of the class
+    name: String,
+    // the discriminator base class, if this is a discriminator variant
+    base: Option<String>,
+    // the discriminator tag and its value, if this is a discriminator variant
+    tag: Option<(String, String)>,
+    // the properties of the class
+    properties: Vec<Property>,
+}
+
+#[derive(Debug)]
+pub struct EnumMember {
+    // the member's name as it appears in Python
+    name: String,
+    // the member's value as it appears in JSON
+    json: String,
+}
+
+#[derive(Debug)]
+pub struct Property {
+    name: String,
+    required: bool,
+    json: String,
+    ast: Ast,
+}
+
+impl Property {
+    // the type of the property's value once an optional property is known to
+    // be present, which for a nullable one already accounts for None
+    fn value_ast(&self) -> &Ast {
+        match &self.ast {
+            Ast::Optional(ast) if !self.required => ast,
+            ast => ast,
+        }
+    }
+}
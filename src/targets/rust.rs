@@ -0,0 +1,413 @@
+use crate::ir::{DeclKind, Discriminator, Field, Ir, Struct, Type};
+use crate::target;
+use failure::Error;
+use inflector::Inflector;
+use std::io::Write;
+use std::path::PathBuf;
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+/// Options for generating Rust.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Options {
+    /// The directory the generated Rust is written into.
+    pub out_dir: PathBuf,
+}
+
+impl Options {
+    /// Construct options for writing Rust into `out_dir`.
+    pub fn new<P: Into<PathBuf>>(out_dir: P) -> Options {
+        Options {
+            out_dir: out_dir.into(),
+        }
+    }
+}
+
+pub struct Target {
+    out_path: PathBuf,
+}
+
+impl target::Target for Target {
+    type Options = Options;
+    type Ast = Ast;
+
+    fn from_options(_root_name: &str, options: &Options) -> Result<Self, Error> {
+        Ok(Target {
+            out_path: options.out_dir.join("mod.rs"),
+        })
+    }
+
+    fn transform(&self, ir: &Ir) -> Result<Ast, Error> {
+        let mut seq = vec![];
+
+        for decl in &ir.decls {
+            match &decl.kind {
+                DeclKind::Struct(Struct { fields, .. }) => {
+                    let props = self.transform_fields(ir, &decl.name, fields);
+                    seq.push(Ast::Struct(decl.name.clone(), props));
+                }
+                DeclKind::Enum(values) => {
+                    let variants = values
+                        .iter()
+                        .map(|val| EnumVariant {
+                            name: self.variant_name(&val.json),
+                            json: val.json.to_owned(),
+                        })
+                        .collect();
+
+                    seq.push(Ast::Enum(decl.name.clone(), variants));
+                }
+                DeclKind::Discriminator(Discriminator { tag, variants }) => {
+                    // Each mapping value becomes a struct of its own, which is
+                    // then wrapped in a newtype variant of an internally-tagged
+                    // enum.
+                    let mut enum_variants = Vec::new();
+                    for variant in variants {
+                        let props = self.transform_fields(ir, &variant.name, &variant.body.fields);
+                        seq.push(Ast::Struct(variant.name.clone(), props));
+
+                        enum_variants.push(DiscriminatorVariant {
+                            name: self.variant_name(&variant.tag_value),
+                            json: variant.tag_value.to_owned(),
+                            ast: Ast::Identifier(variant.name.clone()),
+                        });
+                    }
+
+                    seq.push(Ast::DiscriminatorEnum {
+                        name: decl.name.clone(),
+                        tag_json: tag.to_owned(),
+                        variants: enum_variants,
+                    });
+                }
+                // Rust has no recursive type aliases, so these become a
+                // newtype instead.
+                DeclKind::Alias(ty) if ir.is_recursive_alias(&decl.name) => {
+                    seq.push(Ast::Newtype(
+                        decl.name.clone(),
+                        Box::new(self.transform_type(ty)),
+                    ));
+                }
+                DeclKind::Alias(ty) => {
+                    seq.push(Ast::Typedef(
+                        decl.name.clone(),
+                        Box::new(self.transform_type(ty)),
+                    ));
+                }
+            }
+        }
+
+        Ok(Ast::Sequence(seq))
+    }
+
+    fn serialize(&self, ast: &Ast) -> Result<Vec<(PathBuf, String)>, Error> {
+        let mut out = Vec::new();
+
+        writeln!(out, "use serde::{{Deserialize, Serialize}};")?;
+        writeln!(out)?;
+
+        // Serde deserializes null as `None`, and so needs help to tell a null
+        // property apart from an absent one.
+        if self.uses_deserialize_some(ast) {
+            writeln!(
+                out,
+                "fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>"
+            )?;
+            writeln!(out, "where")?;
+            writeln!(out, "    T: Deserialize<'de>,")?;
+            writeln!(out, "    D: serde::Deserializer<'de>,")?;
+            writeln!(out, "{{")?;
+            writeln!(out, "    Deserialize::deserialize(deserializer).map(Some)")?;
+            writeln!(out, "}}")?;
+            writeln!(out)?;
+        }
+
+        self.serialize_ast(&mut out, ast)?;
+
+        Ok(vec![(self.out_path.clone(), String::from_utf8(out)?)])
+    }
+}
+
+impl Target {
+    fn transform_type(&self, ty: &Type) -> Ast {
+        match ty {
+            Type::Any => Ast::Value,
+            Type::Boolean => Ast::Boolean,
+            Type::String => Ast::String,
+            Type::Timestamp => Ast::DateTime,
+            Type::Int8 => Ast::Int8,
+            Type::Uint8 => Ast::Uint8,
+            Type::Int16 => Ast::Int16,
+            Type::Uint16 => Ast::Uint16,
+            Type::Int32 => Ast::Int32,
+            Type::Uint32 => Ast::Uint32,
+            Type::Float32 => Ast::Float32,
+            Type::Float64 => Ast::Float64,
+            Type::Elements(ty) => Ast::Vec(Box::new(self.transform_type(ty))),
+            Type::Values(ty) => Ast::HashMap(Box::new(self.transform_type(ty))),
+            Type::Ref(name) => Ast::Identifier(name.clone()),
+            Type::Nullable(ty) => Ast::Option(Box::new(self.transform_type(ty))),
+            Type::Custom(ty, _) => self.transform_type(ty),
+        }
+    }
+
+    // Properties which would make a struct hold itself, and so be of infinite
+    // size, are boxed.
+    fn transform_fields(&self, ir: &Ir, owner: &str, fields: &[Field]) -> Vec<Property> {
+        fields
+            .iter()
+            .map(|field| {
+                let ast = self.transform_type(&field.ty);
+                let recursive = field
+                    .ty
+                    .held_directly()
+                    .is_some_and(|held| ir.holds_directly(held, owner));
+                let ast = if recursive { boxed(ast) } else { ast };
+
+                Property {
+                    name: self.field_name(&field.json),
+                    json: field.json.to_owned(),
+                    required: field.required,
+                    ast: if field.required {
+                        ast
+                    } else {
+                        Ast::Option(Box::new(ast))
+                    },
+                }
+            })
+            .collect()
+    }
+
+    // Optional, nullable properties are an `Option<Option<T>>`, where the outer
+    // `Option` is for whether the property is present at all. Optional
+    // properties of any type are much the same, with `serde_json::Value::Null`
+    // standing in for the inner `None`.
+    fn is_optional_nullable(&self, prop: &Property) -> bool {
+        match &prop.ast {
+            Ast::Option(ast) => !prop.required && matches!(**ast, Ast::Option(_) | Ast::Value),
+            _ => false,
+        }
+    }
+
+    fn uses_deserialize_some(&self, ast: &Ast) -> bool {
+        match ast {
+            Ast::Struct(_, props) => props.iter().any(|prop| self.is_optional_nullable(prop)),
+            Ast::Sequence(asts) => asts.iter().any(|ast| self.uses_deserialize_some(ast)),
+            _ => false,
+        }
+    }
+
+    fn serialize_ast(&self, w: &mut dyn Write, ast: &Ast) -> Result<(), Error> {
+        match ast {
+            Ast::Value => write!(w, "serde_json::Value")?,
+            Ast::Boolean => write!(w, "bool")?,
+            Ast::Int8 => write!(w, "i8")?,
+            Ast::Uint8 => write!(w, "u8")?,
+            Ast::Int16 => write!(w, "i16")?,
+            Ast::Uint16 => write!(w, "u16")?,
+            Ast::Int32 => write!(w, "i32")?,
+            Ast::Uint32 => write!(w, "u32")?,
+            Ast::Float32 => write!(w, "f32")?,
+            Ast::Float64 => write!(w, "f64")?,
+            Ast::String => write!(w, "String")?,
+            Ast::DateTime => write!(w, "chrono::DateTime<chrono::FixedOffset>")?,
+            Ast::Option(ast) => {
+                write!(w, "Option<")?;
+                self.serialize_ast(w, ast)?;
+                write!(w, ">")?;
+            }
+            Ast::Box(ast) => {
+                write!(w, "Box<")?;
+                self.serialize_ast(w, ast)?;
+                write!(w, ">")?;
+            }
+            Ast::Vec(ast) => {
+                write!(w, "Vec<")?;
+                self.serialize_ast(w, ast)?;
+                write!(w, ">")?;
+            }
+            Ast::HashMap(ast) => {
+                write!(w, "std::collections::HashMap<String, ")?;
+                self.serialize_ast(w, ast)?;
+                write!(w, ">")?;
+            }
+            Ast::Struct(name, props) => {
+                writeln!(
+                    w,
+                    "#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]"
+                )?;
+                writeln!(w, "pub struct {} {{", name)?;
+                for prop in props {
+                    if self.is_optional_nullable(prop) {
+                        writeln!(
+                            w,
+                            "    #[serde(rename = {:?}, default, skip_serializing_if = \"Option::is_none\", deserialize_with = \"deserialize_some\")]",
+                            prop.json
+                        )?;
+                    } else if !prop.required {
+                        writeln!(
+                            w,
+                            "    #[serde(rename = {:?}, skip_serializing_if = \"Option::is_none\")]",
+                            prop.json
+                        )?;
+                    } else {
+                        writeln!(w, "    #[serde(rename = {:?})]", prop.json)?;
+                    }
+
+                    write!(w, "    pub {}: ", prop.name)?;
+                    self.serialize_ast(w, &prop.ast)?;
+                    writeln!(w, ",")?;
+                }
+                writeln!(w, "}}")?;
+            }
+            Ast::Enum(name, variants) => {
+                writeln!(
+                    w,
+                    "#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]"
+                )?;
+                writeln!(w, "pub enum {} {{", name)?;
+                for variant in variants {
+                    writeln!(w, "    #[serde(rename = {:?})]", variant.json)?;
+                    writeln!(w, "    {},", variant.name)?;
+                }
+                writeln!(w, "}}")?;
+            }
+            Ast::DiscriminatorEnum {
+                name,
+                tag_json,
+                variants,
+            } => {
+                writeln!(
+                    w,
+                    "#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]"
+                )?;
+                writeln!(w, "#[serde(tag = {:?})]", tag_json)?;
+                writeln!(w, "pub enum {} {{", name)?;
+                for variant in variants {
+                    writeln!(w, "    #[serde(rename = {:?})]", variant.json)?;
+                    write!(w, "    {}(", variant.name)?;
+                    self.serialize_ast(w, &variant.ast)?;
+                    writeln!(w, "),")?;
+                }
+                writeln!(w, "}}")?;
+            }
+            Ast::Identifier(id) => write!(w, "{}", id)?,
+            Ast::Newtype(name, ast) => {
+                writeln!(
+                    w,
+                    "#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]"
+                )?;
+                write!(w, "pub struct {}(pub ", name)?;
+                self.serialize_ast(w, ast)?;
+                writeln!(w, ");")?;
+            }
+            Ast::Typedef(name, ast) => {
+                write!(w, "pub type {} = ", name)?;
+                self.serialize_ast(w, ast)?;
+                writeln!(w, ";")?;
+            }
+            Ast::Sequence(asts) => {
+                for ast in asts {
+                    self.serialize_ast(w, ast)?;
+                    writeln!(w)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn field_name(&self, name: &str) -> String {
+        let name = name.to_snake_case();
+        match name.as_ref() {
+            // These keywords cannot be used as raw identifiers.
+            "self" | "super" | "crate" => format!("{}_", name),
+            _ if KEYWORDS.contains(&name.as_ref()) => format!("r#{}", name),
+            _ if !name.starts_with(char::is_alphabetic) => format!("x{}", name),
+            _ => name,
+        }
+    }
+
+    fn variant_name(&self, name: &str) -> String {
+        let name = name.to_pascal_case();
+        match name.as_ref() {
+            "Self" => format!("{}_", name),
+            _ if !name.starts_with(char::is_alphabetic) => format!("X{}", name),
+            _ => name,
+        }
+    }
+}
+
+// Boxes the type an `Option`, if any, is of.
+fn boxed(ast: Ast) -> Ast {
+    match ast {
+        Ast::Option(ast) => Ast::Option(Box::new(boxed(*ast))),
+        _ => Ast::Box(Box::new(ast)),
+    }
+}
+
+#[derive(Debug)]
+pub enum Ast {
+    Value,
+    Boolean,
+    Int8,
+    Uint8,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Float32,
+    Float64,
+    String,
+    DateTime,
+    Option(Box<Ast>),
+    Box(Box<Ast>),
+    Vec(Box<Ast>),
+    HashMap(Box<Ast>),
+    Struct(String, Vec<Property>),
+    Enum(String, Vec<EnumVariant>),
+    DiscriminatorEnum {
+        // the name of the enum
+        name: String,
+        // the name of the tag as it appears in JSON
+        tag_json: String,
+        // the mapping variants
+        variants: Vec<DiscriminatorVariant>,
+    },
+    Identifier(String),
+    Newtype(String, Box<Ast>),
+    Typedef(String, Box<Ast>),
+    Sequence(Vec<Ast>),
+}
+
+#[derive(Debug)]
+pub struct EnumVariant {
+    // the variant's name as it appears in Rust
+    name: String,
+    // the variant's name as it appears in JSON
+    json: String,
+}
+
+#[derive(Debug)]
+pub struct DiscriminatorVariant {
+    // the variant's name as it appears in Rust
+    name: String,
+    // the variant's name as it appears in JSON
+    json: String,
+    // the struct holding the variant's properties
+    ast: Ast,
+}
+
+#[derive(Debug)]
+pub struct Property {
+    name: String,
+    json: String,
+    required: bool,
+    ast: Ast,
+}
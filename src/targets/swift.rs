@@ -0,0 +1,580 @@
+use crate::ir::{DeclKind, Discriminator, Field, Ir, Struct, Type};
+use crate::target;
+use failure::Error;
+use inflector::Inflector;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Options for generating Swift.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Options {
+    /// The directory the generated Swift is written into.
+    pub out_dir: PathBuf,
+}
+
+impl Options {
+    /// Construct options for writing Swift into `out_dir`.
+    pub fn new<P: Into<PathBuf>>(out_dir: P) -> Options {
+        Options {
+            out_dir: out_dir.into(),
+        }
+    }
+}
+
+pub struct Target {
+    out_path: PathBuf,
+}
+
+impl target::Target for Target {
+    type Options = Options;
+    type Ast = Ast;
+
+    fn from_options(root_name: &str, options: &Options) -> Result<Self, Error> {
+        Ok(Target {
+            out_path: options
+                .out_dir
+                .join(format!("{}.swift", root_name.to_pascal_case())),
+        })
+    }
+
+    fn transform(&self, ir: &Ir) -> Result<Ast, Error> {
+        let mut seq = vec![];
+
+        for decl in &ir.decls {
+            match &decl.kind {
+                DeclKind::Struct(Struct { fields, .. }) => {
+                    let props = self.transform_fields(fields);
+
+                    // A struct cannot hold itself, even as an optional, but a
+                    // class can.
+                    let recursive = fields.iter().any(|field| {
+                        field
+                            .ty
+                            .held_directly()
+                            .is_some_and(|held| ir.holds_directly(held, &decl.name))
+                    });
+                    if recursive {
+                        seq.push(Ast::Class(decl.name.clone(), props));
+                    } else {
+                        seq.push(Ast::Struct(decl.name.clone(), props));
+                    }
+                }
+                DeclKind::Enum(values) => {
+                    let cases = values
+                        .iter()
+                        .map(|val| EnumCase {
+                            name: case_name(&val.json),
+                            json: val.json.to_owned(),
+                        })
+                        .collect();
+
+                    seq.push(Ast::Enum(decl.name.clone(), cases));
+                }
+                DeclKind::Discriminator(Discriminator { tag, variants }) => {
+                    let mut cases = Vec::new();
+                    for variant in variants {
+                        let props = self.transform_fields(&variant.body.fields);
+                        seq.push(Ast::Struct(variant.name.clone(), props));
+
+                        cases.push(DiscriminatorCase {
+                            name: case_name(&variant.tag_value),
+                            json: variant.tag_value.to_owned(),
+                            ast: Ast::Identifier(variant.name.clone()),
+                        });
+                    }
+
+                    // A variant which holds the enum is only ever held by it,
+                    // so making the enum indirect is enough to keep the variant
+                    // a struct.
+                    let indirect = variants
+                        .iter()
+                        .any(|variant| ir.holds_directly(&variant.name, &decl.name));
+
+                    seq.push(Ast::DiscriminatorEnum {
+                        name: decl.name.clone(),
+                        tag_json: tag.to_owned(),
+                        indirect,
+                        cases,
+                    });
+                }
+                // A typealias cannot refer back to itself, so these become a
+                // struct instead, which is coded as the value it wraps.
+                DeclKind::Alias(ty) if ir.is_recursive_alias(&decl.name) => {
+                    seq.push(Ast::Wrapper(
+                        decl.name.clone(),
+                        Box::new(self.transform_type(ty)),
+                    ));
+                }
+                DeclKind::Alias(ty) => {
+                    seq.push(Ast::Typealias(
+                        decl.name.clone(),
+                        Box::new(self.transform_type(ty)),
+                    ));
+                }
+            }
+        }
+
+        Ok(Ast::Sequence(seq))
+    }
+
+    fn serialize(&self, ast: &Ast) -> Result<Vec<(PathBuf, String)>, Error> {
+        let mut out = Vec::new();
+
+        writeln!(out, "import Foundation")?;
+        writeln!(out)?;
+
+        // Swift has no built-in Codable type for arbitrary JSON, so one is
+        // generated if the schema uses the empty form anywhere.
+        if self.uses_json_value(ast) {
+            self.serialize_json_value(&mut out)?;
+            writeln!(out)?;
+        }
+
+        self.serialize_ast(&mut out, ast)?;
+
+        Ok(vec![(self.out_path.clone(), String::from_utf8(out)?)])
+    }
+}
+
+impl Target {
+    fn transform_type(&self, ty: &Type) -> Ast {
+        match ty {
+            Type::Any => Ast::JsonValue,
+            Type::Boolean => Ast::Bool,
+            Type::String => Ast::String,
+            Type::Timestamp => Ast::Date,
+            Type::Int8 => Ast::Int8,
+            Type::Uint8 => Ast::UInt8,
+            Type::Int16 => Ast::Int16,
+            Type::Uint16 => Ast::UInt16,
+            Type::Int32 => Ast::Int32,
+            Type::Uint32 => Ast::UInt32,
+            Type::Float32 => Ast::Float,
+            Type::Float64 => Ast::Double,
+            Type::Elements(ty) => Ast::Array(Box::new(self.transform_type(ty))),
+            Type::Values(ty) => Ast::Dictionary(Box::new(self.transform_type(ty))),
+            Type::Ref(name) => Ast::Identifier(name.clone()),
+            Type::Nullable(ty) => Ast::Optional(Box::new(self.transform_type(ty))),
+            Type::Custom(ty, _) => self.transform_type(ty),
+        }
+    }
+
+    fn transform_fields(&self, fields: &[Field]) -> Vec<Property> {
+        fields
+            .iter()
+            .map(|field| {
+                let ast = self.transform_type(&field.ty);
+
+                Property {
+                    name: case_name(&field.json),
+                    json: field.json.to_owned(),
+                    ast: if field.required || matches!(ast, Ast::Optional(_)) {
+                        ast
+                    } else {
+                        Ast::Optional(Box::new(ast))
+                    },
+                }
+            })
+            .collect()
+    }
+
+    fn uses_json_value(&self, ast: &Ast) -> bool {
+        match ast {
+            Ast::JsonValue => true,
+            Ast::Optional(ast) | Ast::Array(ast) | Ast::Dictionary(ast) => {
+                self.uses_json_value(ast)
+            }
+            Ast::Wrapper(_, ast) | Ast::Typealias(_, ast) => self.uses_json_value(ast),
+            Ast::Struct(_, props) | Ast::Class(_, props) => {
+                props.iter().any(|p| self.uses_json_value(&p.ast))
+            }
+            Ast::Sequence(asts) => asts.iter().any(|ast| self.uses_json_value(ast)),
+            _ => false,
+        }
+    }
+
+    fn serialize_json_value(&self, w: &mut dyn Write) -> Result<(), Error> {
+        writeln!(w, "public enum JSONValue: Codable, Equatable {{")?;
+        writeln!(w, "    case null")?;
+        writeln!(w, "    case bool(Bool)")?;
+        writeln!(w, "    case number(Double)")?;
+        writeln!(w, "    case string(String)")?;
+        writeln!(w, "    case array([JSONValue])")?;
+        writeln!(w, "    case object([String: JSONValue])")?;
+        writeln!(w)?;
+        writeln!(w, "    public init(from decoder: Decoder) throws {{")?;
+        writeln!(
+            w,
+            "        let container = try decoder.singleValueContainer()"
+        )?;
+        writeln!(w, "        if container.decodeNil() {{")?;
+        writeln!(w, "            self = .null")?;
+        writeln!(
+            w,
+            "        }} else if let value = try? container.decode(Bool.self) {{"
+        )?;
+        writeln!(w, "            self = .bool(value)")?;
+        writeln!(
+            w,
+            "        }} else if let value = try? container.decode(Double.self) {{"
+        )?;
+        writeln!(w, "            self = .number(value)")?;
+        writeln!(
+            w,
+            "        }} else if let value = try? container.decode(String.self) {{"
+        )?;
+        writeln!(w, "            self = .string(value)")?;
+        writeln!(
+            w,
+            "        }} else if let value = try? container.decode([JSONValue].self) {{"
+        )?;
+        writeln!(w, "            self = .array(value)")?;
+        writeln!(w, "        }} else {{")?;
+        writeln!(
+            w,
+            "            self = .object(try container.decode([String: JSONValue].self))"
+        )?;
+        writeln!(w, "        }}")?;
+        writeln!(w, "    }}")?;
+        writeln!(w)?;
+        writeln!(w, "    public func encode(to encoder: Encoder) throws {{")?;
+        writeln!(w, "        var container = encoder.singleValueContainer()")?;
+        writeln!(w, "        switch self {{")?;
+        writeln!(w, "        case .null:")?;
+        writeln!(w, "            try container.encodeNil()")?;
+        for case in &["bool", "number", "string", "array", "object"] {
+            writeln!(w, "        case .{}(let value):", case)?;
+            writeln!(w, "            try container.encode(value)")?;
+        }
+        writeln!(w, "        }}")?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")?;
+
+        Ok(())
+    }
+
+    fn serialize_ast(&self, w: &mut dyn Write, ast: &Ast) -> Result<(), Error> {
+        match ast {
+            Ast::JsonValue => write!(w, "JSONValue")?,
+            Ast::Bool => write!(w, "Bool")?,
+            Ast::Int8 => write!(w, "Int8")?,
+            Ast::UInt8 => write!(w, "UInt8")?,
+            Ast::Int16 => write!(w, "Int16")?,
+            Ast::UInt16 => write!(w, "UInt16")?,
+            Ast::Int32 => write!(w, "Int32")?,
+            Ast::UInt32 => write!(w, "UInt32")?,
+            Ast::Float => write!(w, "Float")?,
+            Ast::Double => write!(w, "Double")?,
+            Ast::String => write!(w, "String")?,
+            Ast::Date => write!(w, "Date")?,
+            Ast::Optional(ast) => {
+                self.serialize_ast(w, ast)?;
+                write!(w, "?")?;
+            }
+            Ast::Array(ast) => {
+                write!(w, "[")?;
+                self.serialize_ast(w, ast)?;
+                write!(w, "]")?;
+            }
+            Ast::Dictionary(ast) => {
+                write!(w, "[String: ")?;
+                self.serialize_ast(w, ast)?;
+                write!(w, "]")?;
+            }
+            Ast::Struct(name, props) | Ast::Class(name, props) => {
+                if let Ast::Class(..) = ast {
+                    writeln!(w, "public final class {}: Codable {{", name)?;
+                } else {
+                    writeln!(w, "public struct {}: Codable {{", name)?;
+                }
+                for prop in props {
+                    write!(w, "    public var {}: ", prop.name)?;
+                    self.serialize_ast(w, &prop.ast)?;
+                    writeln!(w)?;
+                }
+
+                // Unlike structs, classes have no memberwise initializer.
+                if let Ast::Class(..) = ast {
+                    writeln!(w)?;
+                    write!(w, "    public init(")?;
+                    for (i, prop) in props.iter().enumerate() {
+                        if i != 0 {
+                            write!(w, ", ")?;
+                        }
+                        write!(w, "{}: ", prop.name)?;
+                        self.serialize_ast(w, &prop.ast)?;
+                    }
+                    writeln!(w, ") {{")?;
+                    for prop in props {
+                        writeln!(w, "        self.{} = {}", prop.name, prop.name)?;
+                    }
+                    writeln!(w, "    }}")?;
+                }
+
+                // An enum without any cases cannot have a raw type, so structs
+                // without properties rely on the synthesized coding keys.
+                if !props.is_empty() {
+                    writeln!(w)?;
+                    writeln!(w, "    enum CodingKeys: String, CodingKey {{")?;
+                    for prop in props {
+                        writeln!(w, "        case {} = {:?}", prop.name, prop.json)?;
+                    }
+                    writeln!(w, "    }}")?;
+                }
+                writeln!(w, "}}")?;
+            }
+            Ast::Enum(name, cases) => {
+                writeln!(w, "public enum {}: String, Codable {{", name)?;
+                for case in cases {
+                    writeln!(w, "    case {} = {:?}", case.name, case.json)?;
+                }
+                writeln!(w, "}}")?;
+            }
+            Ast::DiscriminatorEnum {
+                name,
+                tag_json,
+                indirect,
+                cases,
+            } => {
+                if *indirect {
+                    writeln!(w, "public indirect enum {}: Codable {{", name)?;
+                } else {
+                    writeln!(w, "public enum {}: Codable {{", name)?;
+                }
+                for case in cases {
+                    write!(w, "    case {}(", case.name)?;
+                    self.serialize_ast(w, &case.ast)?;
+                    writeln!(w, ")")?;
+                }
+                writeln!(w)?;
+
+                writeln!(w, "    enum CodingKeys: String, CodingKey {{")?;
+                writeln!(w, "        case tag = {:?}", tag_json)?;
+                writeln!(w, "    }}")?;
+                writeln!(w)?;
+
+                writeln!(w, "    public init(from decoder: Decoder) throws {{")?;
+                writeln!(
+                    w,
+                    "        let container = try decoder.container(keyedBy: CodingKeys.self)"
+                )?;
+                writeln!(
+                    w,
+                    "        let tag = try container.decode(String.self, forKey: .tag)"
+                )?;
+                writeln!(w, "        switch tag {{")?;
+                for case in cases {
+                    writeln!(w, "        case {:?}:", case.json)?;
+                    write!(w, "            self = .{}(try ", case.name)?;
+                    self.serialize_ast(w, &case.ast)?;
+                    writeln!(w, "(from: decoder))")?;
+                }
+                writeln!(w, "        default:")?;
+                writeln!(w, "            throw DecodingError.dataCorruptedError(")?;
+                writeln!(w, "                forKey: .tag,")?;
+                writeln!(w, "                in: container,")?;
+                writeln!(
+                    w,
+                    "                debugDescription: \"unknown discriminator tag value: \\(tag)\""
+                )?;
+                writeln!(w, "            )")?;
+                writeln!(w, "        }}")?;
+                writeln!(w, "    }}")?;
+                writeln!(w)?;
+
+                writeln!(w, "    public func encode(to encoder: Encoder) throws {{")?;
+                writeln!(
+                    w,
+                    "        var container = encoder.container(keyedBy: CodingKeys.self)"
+                )?;
+                writeln!(w, "        switch self {{")?;
+                for case in cases {
+                    writeln!(w, "        case .{}(let value):", case.name)?;
+                    writeln!(
+                        w,
+                        "            try container.encode({:?}, forKey: .tag)",
+                        case.json
+                    )?;
+                    writeln!(w, "            try value.encode(to: encoder)")?;
+                }
+                writeln!(w, "        }}")?;
+                writeln!(w, "    }}")?;
+                writeln!(w, "}}")?;
+            }
+            Ast::Identifier(id) => write!(w, "{}", id)?,
+            Ast::Wrapper(name, ast) => {
+                writeln!(w, "public struct {}: Codable {{", name)?;
+                write!(w, "    public var value: ")?;
+                self.serialize_ast(w, ast)?;
+                writeln!(w)?;
+                writeln!(w)?;
+                write!(w, "    public init(_ value: ")?;
+                self.serialize_ast(w, ast)?;
+                writeln!(w, ") {{")?;
+                writeln!(w, "        self.value = value")?;
+                writeln!(w, "    }}")?;
+                writeln!(w)?;
+                writeln!(w, "    public init(from decoder: Decoder) throws {{")?;
+                write!(
+                    w,
+                    "        value = try decoder.singleValueContainer().decode("
+                )?;
+                self.serialize_ast(w, ast)?;
+                writeln!(w, ".self)")?;
+                writeln!(w, "    }}")?;
+                writeln!(w)?;
+                writeln!(w, "    public func encode(to encoder: Encoder) throws {{")?;
+                writeln!(w, "        var container = encoder.singleValueContainer()")?;
+                writeln!(w, "        try container.encode(value)")?;
+                writeln!(w, "    }}")?;
+                writeln!(w, "}}")?;
+            }
+            Ast::Typealias(name, ast) => {
+                write!(w, "public typealias {} = ", name)?;
+                self.serialize_ast(w, ast)?;
+                writeln!(w)?;
+            }
+            Ast::Sequence(asts) => {
+                for ast in asts {
+                    self.serialize_ast(w, ast)?;
+                    writeln!(w)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "Any",
+    "Self",
+    "as",
+    "associatedtype",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "continue",
+    "default",
+    "defer",
+    "deinit",
+    "do",
+    "else",
+    "enum",
+    "extension",
+    "fallthrough",
+    "false",
+    "fileprivate",
+    "for",
+    "func",
+    "guard",
+    "if",
+    "import",
+    "in",
+    "init",
+    "inout",
+    "internal",
+    "is",
+    "let",
+    "nil",
+    "open",
+    "operator",
+    "precedencegroup",
+    "private",
+    "protocol",
+    "public",
+    "repeat",
+    "rethrows",
+    "return",
+    "self",
+    "static",
+    "struct",
+    "subscript",
+    "super",
+    "switch",
+    "throw",
+    "throws",
+    "true",
+    "try",
+    "typealias",
+    "var",
+    "where",
+    "while",
+];
+
+/// The name of a property or enum case, which Swift both spell in camelCase.
+fn case_name(json: &str) -> String {
+    let name = json.to_camel_case();
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("`{}`", name)
+    } else if !name.starts_with(char::is_alphabetic) {
+        format!("x{}", name)
+    } else {
+        name
+    }
+}
+
+#[derive(Debug)]
+pub enum Ast {
+    JsonValue,
+    Bool,
+    Int8,
+    UInt8,
+    Int16,
+    UInt16,
+    Int32,
+    UInt32,
+    Float,
+    Double,
+    String,
+    Date,
+    Optional(Box<Ast>),
+    Array(Box<Ast>),
+    Dictionary(Box<Ast>),
+    Struct(String, Vec<Property>),
+    // a struct which holds itself, and so must be a class
+    Class(String, Vec<Property>),
+    Enum(String, Vec<EnumCase>),
+    DiscriminatorEnum {
+        // the name of the enum
+        name: String,
+        // the name of the tag as it appears in JSON
+        tag_json: String,
+        // whether a variant holds the enum, and so must be stored indirectly
+        indirect: bool,
+        // the mapping variants, as cases with an associated value
+        cases: Vec<DiscriminatorCase>,
+    },
+    Identifier(String),
+    Wrapper(String, Box<Ast>),
+    Typealias(String, Box<Ast>),
+    Sequence(Vec<Ast>),
+}
+
+#[derive(Debug)]
+pub struct EnumCase {
+    // the case's name as it appears in Swift
+    name: String,
+    // the case's value as it appears in JSON
+    json: String,
+}
+
+#[derive(Debug)]
+pub struct DiscriminatorCase {
+    // the case's name as it appears in Swift
+    name: String,
+    // the tag value as it appears in JSON
+    json: String,
+    // the struct holding the variant's properties
+    ast: Ast,
+}
+
+#[derive(Debug)]
+pub struct Property {
+    name: String,
+    json: String,
+    ast: Ast,
+}
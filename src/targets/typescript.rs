@@ -1,215 +1,302 @@
+use crate::ir::{DeclKind, Discriminator, Field, Ir, Struct, Type};
 use crate::target;
-use clap::{App, Arg, ArgMatches};
-use failure::format_err;
 use failure::Error;
-use inflector::Inflector;
-use jddf::{Form, Schema, Type};
-use std::fs::File;
-use std::io::{BufWriter, Write};
+use std::io::Write;
 use std::path::PathBuf;
 
+/// Options for generating TypeScript.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Options {
+    /// The directory the generated TypeScript is written into.
+    pub out_dir: PathBuf,
+
+    /// The path of the schema, which is recorded in the generated code.
+    pub source: Option<PathBuf>,
+
+    /// Whether to turn ESLint off for the generated code.
+    pub eslint_disable: bool,
+}
+
+impl Options {
+    /// Construct options for writing TypeScript into `out_dir`, with no source
+    /// recorded and ESLint left on.
+    pub fn new<P: Into<PathBuf>>(out_dir: P) -> Options {
+        Options {
+            out_dir: out_dir.into(),
+            source: None,
+            eslint_disable: false,
+        }
+    }
+}
+
 pub struct Target {
     out_path: PathBuf,
-    root_name: String,
+    source: Option<PathBuf>,
+    eslint_disable: bool,
 }
 
 impl target::Target for Target {
+    type Options = Options;
     type Ast = Ast;
 
-    fn args<'a, 'b>(app: App<'a, 'b>) -> App<'a, 'b> {
-        app.arg(
-            Arg::with_name("ts-out")
-                .help("Typescript output directory")
-                .takes_value(true)
-                .long("ts-out"),
-        )
+    fn from_options(_root_name: &str, options: &Options) -> Result<Self, Error> {
+        Ok(Target {
+            out_path: options.out_dir.join("index.ts"),
+            source: options.source.clone(),
+            eslint_disable: options.eslint_disable,
+        })
     }
 
-    fn from_args(matches: &ArgMatches) -> Result<Option<Self>, Error> {
-        if let Some(ts_out) = matches.value_of("ts-out") {
-            let out_path = PathBuf::from(ts_out).join("index.ts");
-
-            // Infer a root name from the file name of the input schema.
-            let input = PathBuf::from(matches.value_of("INPUT").unwrap());
+    fn transform(&self, ir: &Ir) -> Result<Ast, Error> {
+        let mut seq = vec![];
 
-            let input_file_name = input
-                .file_name()
-                .ok_or(format_err!("Could not infer file name from INPUT"))?;
+        for decl in &ir.decls {
+            match &decl.kind {
+                DeclKind::Struct(Struct {
+                    fields,
+                    allow_additional,
+                }) => {
+                    let props = self.transform_fields(fields);
+                    seq.push(self.documented(
+                        &decl.description,
+                        Ast::Interface(decl.name.clone(), props, *allow_additional),
+                    ));
+                }
+                DeclKind::Enum(values) => {
+                    let consts = values
+                        .iter()
+                        .map(|val| Ast::Constant(val.json.to_owned()))
+                        .collect();
+
+                    // The members of a union can't be documented individually,
+                    // so their descriptions are listed on the union itself.
+                    let mut docs: Vec<_> = decl.description.iter().cloned().collect();
+                    let members: Vec<_> = values
+                        .iter()
+                        .filter_map(|val| {
+                            let description = val.description.as_ref()?;
+                            Some(format!(
+                                "- {}: {}",
+                                serde_json::to_string(&val.json).ok()?,
+                                description
+                            ))
+                        })
+                        .collect();
+                    if !members.is_empty() {
+                        docs.push(members.join("\n"));
+                    }
 
-            let input_file_name = input_file_name
-                .to_str()
-                .ok_or(format_err!("Could not convert INPUT file name to UTF-8"))?;
+                    let docs = if docs.is_empty() {
+                        None
+                    } else {
+                        Some(docs.join("\n\n"))
+                    };
 
-            let root_name = input_file_name.split(".").next().unwrap().to_pascal_case();
+                    seq.push(self.documented(
+                        &docs,
+                        Ast::Typedef(decl.name.clone(), Box::new(Ast::Union(consts))),
+                    ));
+                }
+                DeclKind::Discriminator(Discriminator { tag, variants }) => {
+                    let mut cases = Vec::new();
+                    for variant in variants {
+                        // Each variant is an interface just like those for the
+                        // properties form, but with one additional property
+                        // for the discriminator tag.
+                        let mut props = Vec::new();
+                        props.push(Property {
+                            name: self.property_name(tag),
+                            required: true,
+                            description: None,
+                            ast: Ast::Constant(variant.tag_value.to_owned()),
+                        });
+                        props.extend(self.transform_fields(&variant.body.fields));
+
+                        seq.push(self.documented(
+                            &variant.description,
+                            Ast::Interface(
+                                variant.name.clone(),
+                                props,
+                                variant.body.allow_additional,
+                            ),
+                        ));
+                        cases.push(Ast::Identifier(variant.name.clone()));
+                    }
 
-            Ok(Some(Target {
-                out_path,
-                root_name,
-            }))
-        } else {
-            Ok(None)
+                    seq.push(self.documented(
+                        &decl.description,
+                        Ast::Typedef(decl.name.clone(), Box::new(Ast::Union(cases))),
+                    ));
+                }
+                DeclKind::Alias(ty) => {
+                    seq.push(self.documented(
+                        &decl.description,
+                        Ast::Typedef(decl.name.clone(), Box::new(self.transform_type(ty))),
+                    ));
+                }
+            }
         }
+
+        Ok(Ast::Sequence(seq))
     }
 
-    fn transform(&self, schema: &Schema) -> Result<Ast, Error> {
-        let mut seq = vec![];
+    fn serialize(&self, ast: &Ast) -> Result<Vec<(PathBuf, String)>, Error> {
+        let mut out = Vec::new();
 
-        if let Some(defs) = schema.definitions() {
-            for (name, schema) in defs {
-                let ast = self.transform_subschema(&mut seq, &mut vec![name], schema)?;
-                self.ensure_has_name(Some(name), &mut seq, ast);
+        for line in target::header(self.source.as_deref()) {
+            if line.is_empty() {
+                writeln!(out, "//")?;
+            } else {
+                writeln!(out, "// {}", line)?;
             }
         }
+        if self.eslint_disable {
+            writeln!(out, "/* eslint-disable */")?;
+        }
+        writeln!(out)?;
 
-        let ast = self.transform_subschema(&mut seq, &mut vec![&self.root_name], schema)?;
-        self.ensure_has_name(None, &mut seq, ast);
-
-        Ok(Ast::Sequence(seq))
-    }
-
-    fn serialize(&self, ast: &Ast) -> Result<(), Error> {
-        let mut out = BufWriter::new(File::create(self.out_path.clone())?);
         self.serialize_ast(&mut out, ast)?;
 
-        Ok(())
+        Ok(vec![(self.out_path.clone(), String::from_utf8(out)?)])
     }
 }
 
 impl Target {
-    fn transform_subschema<'a>(
-        &self,
-        seq: &mut Vec<Ast>,
-        name: &mut Vec<&'a str>,
-        schema: &'a Schema,
-    ) -> Result<Ast, Error> {
-        match schema.form() {
-            Form::Empty => Ok(Ast::Any),
-            Form::Ref(def) => Ok(Ast::Identifier(self.name(&[&def]))),
-            Form::Type(Type::Boolean) => Ok(Ast::Boolean),
-            Form::Type(Type::String) | Form::Type(Type::Timestamp) => Ok(Ast::String),
-            Form::Type(Type::Int8)
-            | Form::Type(Type::Uint8)
-            | Form::Type(Type::Int16)
-            | Form::Type(Type::Uint16)
-            | Form::Type(Type::Int32)
-            | Form::Type(Type::Uint32)
-            | Form::Type(Type::Float32)
-            | Form::Type(Type::Float64) => Ok(Ast::Number),
-            Form::Enum(vals) => Ok(Ast::Union(
-                vals.iter()
-                    .map(|val| Ast::Constant(val.to_owned()))
-                    .collect(),
-            )),
-            Form::Elements(schema) => Ok(Ast::Array(Box::new(
-                self.transform_subschema(seq, name, schema)?,
-            ))),
-            Form::Properties {
-                required, optional, ..
-            } => {
-                let mut props = Vec::new();
-                for (prop, schema) in required {
-                    name.push(prop);
-                    let ast = self.transform_subschema(seq, name, schema)?;
-                    name.pop();
-
-                    props.push((prop.to_owned(), true, ast));
-                }
-
-                for (prop, schema) in optional {
-                    name.push(prop);
-                    let ast = self.transform_subschema(seq, name, schema)?;
-                    name.pop();
-
-                    props.push((prop.to_owned(), false, ast));
-                }
-
-                let id = self.name(name);
-                seq.push(Ast::Interface(id.clone(), props));
-                Ok(Ast::Identifier(id))
-            }
-            Form::Discriminator(tag, mapping) => {
-                let mut cases = Vec::new();
-                for (val, schema) in mapping {
-                    name.push(val);
-
-                    // We know that `schema` is of the properties form. We want
-                    // to do almost exactly the same thing as we did for the
-                    // properties form, but with one additional property, for
-                    // the discriminator tag.
-                    if let Form::Properties {
-                        required, optional, ..
-                    } = schema.form()
-                    {
-                        let mut props = Vec::new();
-                        props.push((tag.to_owned(), true, Ast::Constant(val.to_owned())));
-
-                        for (prop, schema) in required {
-                            name.push(prop);
-                            let ast = self.transform_subschema(seq, name, schema)?;
-                            name.pop();
+    fn transform_type(&self, ty: &Type) -> Ast {
+        match ty {
+            Type::Any => Ast::Any,
+            Type::Boolean => Ast::Boolean,
+            Type::String | Type::Timestamp => Ast::String,
+            Type::Int8
+            | Type::Uint8
+            | Type::Int16
+            | Type::Uint16
+            | Type::Int32
+            | Type::Uint32
+            | Type::Float32
+            | Type::Float64 => Ast::Number,
+            Type::Elements(ty) => Ast::Array(Box::new(self.transform_type(ty))),
+            Type::Values(ty) => Ast::Map(Box::new(self.transform_type(ty))),
+            Type::Ref(name) => Ast::Identifier(name.clone()),
+            Type::Nullable(ty) => Ast::Union(vec![self.transform_type(ty), Ast::Null]),
+            Type::Custom(ty, custom) => match &custom.ts_type {
+                Some(ts_type) => Ast::Identifier(ts_type.clone()),
+                None => self.transform_type(ty),
+            },
+        }
+    }
 
-                            props.push((prop.to_owned(), true, ast));
-                        }
+    fn transform_fields(&self, fields: &[Field]) -> Vec<Property> {
+        fields
+            .iter()
+            .map(|field| Property {
+                name: self.property_name(&field.json),
+                required: field.required,
+                description: field.description.clone(),
+                ast: self.transform_type(&field.ty),
+            })
+            .collect()
+    }
 
-                        for (prop, schema) in optional {
-                            name.push(prop);
-                            let ast = self.transform_subschema(seq, name, schema)?;
-                            name.pop();
+    fn documented(&self, description: &Option<String>, ast: Ast) -> Ast {
+        match description {
+            Some(description) => Ast::Documented(description.clone(), Box::new(ast)),
+            None => ast,
+        }
+    }
 
-                            props.push((prop.to_owned(), false, ast));
-                        }
+    fn serialize_doc(&self, w: &mut dyn Write, indent: &str, doc: &str) -> Result<(), Error> {
+        // The comment would otherwise end early.
+        let doc = doc.replace("*/", "*\\/");
+        let lines: Vec<_> = doc.lines().map(str::trim_end).collect();
 
-                        let id = self.name(name);
-                        seq.push(Ast::Interface(id.clone(), props));
-                        cases.push(Ast::Identifier(id));
-                    }
-
-                    name.pop();
+        if let [line] = lines.as_slice() {
+            writeln!(w, "{}/** {} */", indent, line)?;
+        } else {
+            writeln!(w, "{}/**", indent)?;
+            for line in lines {
+                if line.is_empty() {
+                    writeln!(w, "{} *", indent)?;
+                } else {
+                    writeln!(w, "{} * {}", indent, line)?;
                 }
-
-                Ok(Ast::Union(cases))
             }
-            Form::Values(schema) => Ok(Ast::Map(Box::new(
-                self.transform_subschema(seq, name, schema)?,
-            ))),
+            writeln!(w, "{} */", indent)?;
         }
+
+        Ok(())
     }
 
-    fn ensure_has_name(&self, name: Option<&str>, seq: &mut Vec<Ast>, ast: Ast) {
-        match ast {
-            Ast::Identifier(_) => {}
-            _ => {
-                let id = self.name(&[name.unwrap_or(&self.root_name)]);
-                seq.push(Ast::Typedef(id, Box::new(ast)));
+    /// The name of an interface member, quoted if the JSON name is not a valid
+    /// identifier by itself.
+    ///
+    /// Keywords are allowed as member names, so they need no special handling.
+    fn property_name(&self, json: &str) -> String {
+        let mut chars = json.chars();
+        let is_identifier = match chars.next() {
+            Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => {
+                chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
             }
+            _ => false,
+        };
+
+        if is_identifier {
+            json.to_owned()
+        } else {
+            serde_json::to_string(json).unwrap()
         }
     }
 
-    fn serialize_ast(&self, w: &mut Write, ast: &Ast) -> Result<(), Error> {
+    fn serialize_ast(&self, w: &mut dyn Write, ast: &Ast) -> Result<(), Error> {
         match ast {
             Ast::Any => write!(w, "any")?,
             Ast::Boolean => write!(w, "boolean")?,
             Ast::Number => write!(w, "number")?,
             Ast::String => write!(w, "string")?,
-            Ast::Constant(s) => write!(w, "{:?}", s)?,
+            Ast::Null => write!(w, "null")?,
+            Ast::Constant(s) => write!(w, "{}", serde_json::to_string(s)?)?,
             Ast::Array(ast) => {
-                self.serialize_ast(w, ast)?;
-                write!(w, "[]")?;
+                // Without parentheses, `T | null[]` would be an array of nulls.
+                if let Ast::Union(_) = **ast {
+                    write!(w, "(")?;
+                    self.serialize_ast(w, ast)?;
+                    write!(w, ")[]")?;
+                } else {
+                    self.serialize_ast(w, ast)?;
+                    write!(w, "[]")?;
+                }
             }
             Ast::Map(ast) => {
                 write!(w, "{{ [name: string]: ")?;
                 self.serialize_ast(w, ast)?;
                 write!(w, "}}")?;
             }
-            Ast::Interface(name, props) => {
+            Ast::Interface(name, props, allow_additional) => {
                 writeln!(w, "export interface {} {{", name)?;
-                for (name, required, ast) in props {
-                    write!(w, "  {}{}: ", name, if *required { "" } else { "?" })?;
-                    self.serialize_ast(w, ast)?;
+                for prop in props {
+                    if let Some(description) = &prop.description {
+                        self.serialize_doc(w, "  ", description)?;
+                    }
+
+                    write!(
+                        w,
+                        "  {}{}: ",
+                        prop.name,
+                        if prop.required { "" } else { "?" }
+                    )?;
+                    self.serialize_ast(w, &prop.ast)?;
                     writeln!(w, ";")?;
                 }
+
+                // Without an index signature, TypeScript rejects object
+                // literals with properties the interface does not declare.
+                if *allow_additional {
+                    writeln!(w, "  [key: string]: unknown;")?;
+                }
                 writeln!(w, "}}")?;
             }
+            Ast::Union(asts) if asts.is_empty() => write!(w, "never")?,
             Ast::Union(asts) => {
                 for ast in &asts[..asts.len() - 1] {
                     self.serialize_ast(w, ast)?;
@@ -218,6 +305,10 @@ impl Target {
                 self.serialize_ast(w, &asts[asts.len() - 1])?;
             }
             Ast::Identifier(id) => write!(w, "{}", id)?,
+            Ast::Documented(doc, ast) => {
+                self.serialize_doc(w, "", doc)?;
+                self.serialize_ast(w, ast)?;
+            }
             Ast::Typedef(name, ast) => {
                 write!(w, "export type {} = ", name)?;
                 self.serialize_ast(w, ast)?;
@@ -233,10 +324,6 @@ impl Target {
 
         Ok(())
     }
-
-    fn name(&self, name: &[&str]) -> String {
-        name.join("_").to_pascal_case()
-    }
 }
 
 #[derive(Debug)]
@@ -245,12 +332,26 @@ pub enum Ast {
     Boolean,
     Number,
     String,
+    Null,
     Constant(String),
     Array(Box<Ast>),
     Map(Box<Ast>),
-    Interface(String, Vec<(String, bool, Ast)>),
+    // the name of the interface, its properties, and whether it allows
+    // properties besides those
+    Interface(String, Vec<Property>, bool),
     Union(Vec<Ast>),
     Identifier(String),
     Typedef(String, Box<Ast>),
     Sequence(Vec<Ast>),
+    // a JSDoc comment, and the declaration it documents
+    Documented(String, Box<Ast>),
+}
+
+#[derive(Debug)]
+pub struct Property {
+    // the member's name, quoted if need be
+    name: String,
+    required: bool,
+    description: Option<String>,
+    ast: Ast,
 }
@@ -0,0 +1,54 @@
+mod common;
+
+const SCHEMA: &str = r#"{
+    "properties": {
+        "open": {
+            "properties": { "a": { "type": "string" } },
+            "additionalProperties": true
+        },
+        "closed": {
+            "properties": { "b": { "type": "string" } }
+        }
+    }
+}"#;
+
+fn golang(preserve_additional: bool) -> String {
+    common::golang(SCHEMA, |options| {
+        options.preserve_additional = preserve_additional
+    })
+}
+
+#[test]
+fn typescript_index_signature_only_when_allowed() {
+    let out = common::typescript(SCHEMA, |_| {});
+    assert!(
+        out.contains("export interface RootOpen {\n  a: string;\n  [key: string]: unknown;\n}\n")
+    );
+    assert!(out.contains("export interface RootClosed {\n  b: string;\n}\n"));
+}
+
+#[test]
+fn golang_rejects_unknown_properties_when_disallowed() {
+    let out = golang(false);
+    assert!(out.contains("func (v *RootClosed) UnmarshalJSON(b []byte) error {\n"));
+    assert!(out.contains("return fmt.Errorf(\"root: unknown property %q in RootClosed\", key)"));
+    assert!(!out.contains("unknown property %q in RootOpen"));
+    assert!(!out.contains("Extra"));
+}
+
+#[test]
+fn golang_captures_unknown_properties_when_asked() {
+    let out = golang(true);
+    assert!(out.contains("\tExtra map[string]json.RawMessage `json:\"-\"`\n"));
+    assert!(out.contains("func (v *RootOpen) UnmarshalJSON(b []byte) error {\n"));
+    assert!(out.contains("func (v RootOpen) MarshalJSON() ([]byte, error) {\n"));
+    assert!(!out.contains("func (v RootClosed) MarshalJSON"));
+}
+
+#[test]
+fn golang_extra_must_not_collide_with_a_property() {
+    let schema =
+        r#"{ "properties": { "extra": { "type": "string" } }, "additionalProperties": true }"#;
+    let options = common::golang_options(|options| options.preserve_additional = true);
+    assert!(common::try_generate(schema, &options).is_err());
+}
@@ -0,0 +1,112 @@
+//! Fixtures shared between the integration tests.
+//!
+//! Each test file only uses some of these, hence the allowance for dead code.
+
+#![allow(dead_code)]
+
+use failure::Error;
+use jddf::{Schema, SerdeSchema};
+use jddf_codegen::targets::{csharp, golang, java, kotlin, python, rust, swift, typescript};
+use jddf_codegen::Options;
+
+pub fn parse(schema: &str) -> Schema {
+    let serde_schema: SerdeSchema = serde_json::from_str(schema).unwrap();
+    Schema::from_serde(serde_schema).unwrap()
+}
+
+/// Generates code for a schema, with the path of each file as a string.
+pub fn try_generate(schema: &str, options: &Options) -> Result<Vec<(String, String)>, Error> {
+    let files = jddf_codegen::generate(&parse(schema), options)?;
+    Ok(files
+        .into_iter()
+        .map(|(path, out)| (path.to_str().unwrap().to_owned(), out))
+        .collect())
+}
+
+pub fn generate(schema: &str, options: &Options) -> Vec<(String, String)> {
+    try_generate(schema, options).unwrap()
+}
+
+/// Generates code for a schema, which is expected to make a single file.
+pub fn generate_one(schema: &str, options: &Options) -> String {
+    let mut files = generate(schema, options);
+    assert_eq!(1, files.len());
+    files.remove(0).1
+}
+
+/// Options for generating Go alone, for a schema named "root", into a package
+/// of the same name. The defaults can be changed by `configure`.
+pub fn golang_options(configure: impl FnOnce(&mut golang::Options)) -> Options {
+    let mut golang = golang::Options::new("golang", "root");
+    configure(&mut golang);
+
+    let mut options = Options::new("root");
+    options.golang = Some(golang);
+    options
+}
+
+pub fn golang(schema: &str, configure: impl FnOnce(&mut golang::Options)) -> String {
+    generate_one(schema, &golang_options(configure))
+}
+
+/// Options for generating TypeScript alone, for a schema named "root". The
+/// defaults can be changed by `configure`.
+pub fn typescript_options(configure: impl FnOnce(&mut typescript::Options)) -> Options {
+    let mut typescript = typescript::Options::new("ts");
+    configure(&mut typescript);
+
+    let mut options = Options::new("root");
+    options.typescript = Some(typescript);
+    options
+}
+
+pub fn typescript(schema: &str, configure: impl FnOnce(&mut typescript::Options)) -> String {
+    generate_one(schema, &typescript_options(configure))
+}
+
+/// Options for generating Python alone, for a schema named "root".
+pub fn python_options() -> Options {
+    let mut options = Options::new("root");
+    options.python = Some(python::Options::new("python"));
+    options
+}
+
+pub fn python(schema: &str) -> String {
+    generate_one(schema, &python_options())
+}
+
+/// Java for a schema named "root", in a package of the same name, with the
+/// path of each file as a string.
+pub fn java(schema: &str) -> Vec<(String, String)> {
+    let mut options = Options::new("root");
+    options.java = Some(java::Options::new("java", "root"));
+    generate(schema, &options)
+}
+
+/// C# for a schema named "root", in a namespace of the same name.
+pub fn csharp(schema: &str) -> String {
+    let mut options = Options::new("root");
+    options.csharp = Some(csharp::Options::new("csharp", "Root"));
+    generate_one(schema, &options)
+}
+
+/// Rust for a schema named "root".
+pub fn rust(schema: &str) -> String {
+    let mut options = Options::new("root");
+    options.rust = Some(rust::Options::new("rust"));
+    generate_one(schema, &options)
+}
+
+/// Kotlin for a schema named "root", in a package of the same name.
+pub fn kotlin(schema: &str) -> String {
+    let mut options = Options::new("root");
+    options.kotlin = Some(kotlin::Options::new("kotlin", "root"));
+    generate_one(schema, &options)
+}
+
+/// Swift for a schema named "root".
+pub fn swift(schema: &str) -> String {
+    let mut options = Options::new("root");
+    options.swift = Some(swift::Options::new("swift"));
+    generate_one(schema, &options)
+}
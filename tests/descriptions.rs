@@ -0,0 +1,36 @@
+mod common;
+
+const SCHEMA: &str = r#"{
+    "metadata": { "description": "The root.\nSee also: */ here." },
+    "properties": {
+        "color": {
+            "metadata": {
+                "description": "A color.",
+                "enumDescriptions": { "RED": "Like a tomato." }
+            },
+            "enum": ["RED", "GREEN"]
+        }
+    }
+}"#;
+
+#[test]
+fn typescript_emits_jsdoc() {
+    let out = common::typescript(SCHEMA, |_| {});
+    assert!(
+        out.contains("/**\n * The root.\n * See also: *\\/ here.\n */\nexport interface Root {\n")
+    );
+    assert!(out.contains("  /** A color. */\n  color: RootColor;\n"));
+    assert!(out.contains(
+        "/**\n * A color.\n *\n * - \"RED\": Like a tomato.\n */\nexport type RootColor = \"GREEN\" | \"RED\";\n"
+    ));
+}
+
+#[test]
+fn golang_emits_doc_comments() {
+    let out = common::golang(SCHEMA, |_| {});
+    assert!(out.contains("// The root.\n// See also: */ here.\ntype Root struct {\n"));
+    assert!(out.contains("\t// A color.\n\tColor RootColor `json:\"color\"`\n"));
+    assert!(out.contains("// A color.\ntype RootColor string\n"));
+    assert!(out.contains("// Like a tomato.\nconst RootColorRED RootColor = \"RED\"\n"));
+    assert!(out.contains("\nconst RootColorGREEN RootColor = \"GREEN\"\n"));
+}
@@ -0,0 +1,71 @@
+use jddf::{Schema, SerdeSchema};
+use jddf_codegen::targets::{csharp, golang, java, kotlin, python, rust, swift, typescript};
+use jddf_codegen::Options;
+use std::fs;
+use std::path::PathBuf;
+
+const EXAMPLES: &[&str] = &["user", "message", "gamut", "profile"];
+
+fn parse_schema(example: &str) -> Schema {
+    let path = format!("examples/{}/{}.jddf.json", example, example);
+    let serde_schema: SerdeSchema =
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+
+    Schema::from_serde(serde_schema).unwrap()
+}
+
+// The same options the Makefile uses to generate the checked-in examples.
+fn options(example: &str) -> Options {
+    let dir = PathBuf::from("examples").join(example);
+    let source = dir.join(format!("{}.jddf.json", example));
+
+    let mut typescript = typescript::Options::new(dir.join("ts"));
+    typescript.source = Some(source.clone());
+    let mut golang = golang::Options::new(dir.join("golang"), example);
+    golang.source = Some(source);
+
+    let package = format!("com.example.{}", example);
+    let namespace = format!("Example.{}{}", example[..1].to_uppercase(), &example[1..]);
+
+    let mut options = Options::new(example);
+    options.typescript = Some(typescript);
+    options.golang = Some(golang);
+    options.rust = Some(rust::Options::new(dir.join("rust")));
+    options.python = Some(python::Options::new(dir.join("python")));
+    options.java = Some(java::Options::new(dir.join("java"), &package));
+    options.kotlin = Some(kotlin::Options::new(dir.join("kotlin"), &package));
+    options.swift = Some(swift::Options::new(dir.join("swift")));
+    options.csharp = Some(csharp::Options::new(dir.join("csharp"), &namespace));
+
+    options
+}
+
+#[test]
+fn generating_twice_is_byte_identical() {
+    for example in EXAMPLES {
+        // Parse the schema afresh each time, so that its hash maps are seeded
+        // differently between the two runs.
+        let first = jddf_codegen::generate(&parse_schema(example), &options(example)).unwrap();
+        let second = jddf_codegen::generate(&parse_schema(example), &options(example)).unwrap();
+
+        assert_eq!(first, second, "output for {} differs between runs", example);
+    }
+}
+
+#[test]
+fn checked_in_examples_are_up_to_date() {
+    for example in EXAMPLES {
+        let files = jddf_codegen::generate(&parse_schema(example), &options(example)).unwrap();
+
+        for (path, contents) in files {
+            let expected = fs::read_to_string(&path)
+                .unwrap_or_else(|err| panic!("reading {}: {}", path.display(), err));
+
+            assert!(
+                expected == contents,
+                "{} is out of date; regenerate it with `make`",
+                path.display()
+            );
+        }
+    }
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use jddf_codegen::targets::golang::DiscriminatorStyle;
+use jddf_codegen::Options;
+
+const SCHEMA: &str = r#"{
+    "discriminator": {
+        "tag": "kind",
+        "mapping": {
+            "circle": { "properties": { "size": { "type": "float64" } } },
+            "square": { "properties": { "size": { "type": "float64" } } }
+        }
+    }
+}"#;
+
+fn options(discriminator_style: DiscriminatorStyle) -> Options {
+    let mut options =
+        common::golang_options(|options| options.discriminator_style = discriminator_style);
+    options.root_name = "shape".to_owned();
+    options
+}
+
+fn golang(schema: &str, discriminator_style: DiscriminatorStyle) -> String {
+    common::generate_one(schema, &options(discriminator_style))
+}
+
+#[test]
+fn golang_embedded_style_embeds_every_variant() {
+    let out = golang(SCHEMA, DiscriminatorStyle::Embedded);
+    assert!(out.contains(
+        "type Shape struct {\n\tKind ShapeKind `json:\"kind\"`\n\tShapeCircle\n\tShapeSquare\n}\n"
+    ));
+}
+
+#[test]
+fn golang_interface_style_wraps_a_sealed_interface() {
+    let out = golang(SCHEMA, DiscriminatorStyle::Interface);
+    assert!(out.contains("type Shape struct {\n\tValue ShapeVariant\n}\n"));
+    assert!(out.contains("type ShapeVariant interface {\n\tisShapeVariant()\n}\n"));
+    assert!(out.contains("func (ShapeCircle) isShapeVariant() {}\n"));
+    assert!(out.contains("func (ShapeSquare) isShapeVariant() {}\n"));
+    assert!(out.contains("\tcase ShapeCircle:\n"));
+    assert!(out.contains("\tcase \"square\":\n\t\tvar value ShapeSquare\n"));
+    assert!(!out.contains("ShapeKind"));
+}
+
+#[test]
+fn golang_interface_name_must_be_free() {
+    let schema = r#"{
+        "discriminator": {
+            "tag": "kind",
+            "mapping": { "variant": { "properties": {} } }
+        }
+    }"#;
+
+    let options = options(DiscriminatorStyle::Interface);
+    assert!(common::try_generate(schema, &options).is_err());
+}
+
+#[test]
+fn golang_interface_style_accepts_pointers_to_variants() {
+    let out = golang(SCHEMA, DiscriminatorStyle::Interface);
+    assert!(out.contains(
+        "\tcase ShapeCircle:\n\t\treturn marshalVariant(\"kind\", \"circle\", &value)\n\tcase *ShapeCircle:\n\t\tif value != nil {\n\t\t\treturn marshalVariant(\"kind\", \"circle\", value)\n\t\t}\n"
+    ));
+    assert!(out.contains(
+        "\tcase *ShapeCircle:\n\t\tif value != nil {\n\t\t\tvalue.validate(path, errs)\n\t\t\treturn\n\t\t}\n"
+    ));
+}
@@ -0,0 +1,31 @@
+mod common;
+
+fn golang(schema: &str) -> String {
+    common::golang(schema, |_| {})
+}
+
+#[test]
+fn golang_enums_are_defined_types() {
+    let out = golang(r#"{ "enum": ["b", "a"] }"#);
+    assert!(out.contains("type Root string\n"));
+    assert!(out.contains("const RootA Root = \"a\"\n"));
+    assert!(out.contains("const RootB Root = \"b\"\n"));
+    assert!(out.contains("var AllRootValues = []Root{\n\tRootA,\n\tRootB,\n}\n"));
+    assert!(out.contains("func (v Root) String() string {\n"));
+    assert!(out.contains("func (v Root) IsValid() bool {\n\tswitch v {\n\tcase RootA, RootB:\n"));
+}
+
+#[test]
+fn golang_enums_validate_when_encoded_and_decoded() {
+    let out = golang(r#"{ "enum": ["a"] }"#);
+    assert!(out.contains("type InvalidEnumValueError struct {\n"));
+    assert!(out.contains("func (v Root) MarshalJSON() ([]byte, error) {\n"));
+    assert!(out.contains("func (v *Root) UnmarshalJSON(b []byte) error {\n"));
+    assert!(out.contains("\t\treturn &InvalidEnumValueError{Type: \"Root\", Value: s}\n"));
+}
+
+#[test]
+fn golang_error_type_only_when_needed() {
+    let out = golang(r#"{ "type": "string" }"#);
+    assert!(!out.contains("InvalidEnumValueError"));
+}
@@ -0,0 +1,200 @@
+mod common;
+
+use jddf_codegen::targets::golang::DiscriminatorStyle;
+use std::fs;
+use std::process::Command;
+
+// Covers a nested discriminator, a variant without properties, one with a
+// MarshalJSON of its own and a property whose field is named Tag, and integers
+// which encoding/json would not decode by itself.
+const SCHEMA: &str = r#"{
+    "definitions": {
+        "shape": {
+            "discriminator": {
+                "tag": "kind",
+                "mapping": {
+                    "circle": { "properties": { "radius": { "type": "float64" } } },
+                    "empty": { "properties": {} },
+                    "extra": {
+                        "properties": { "tag": { "type": "string" } },
+                        "additionalProperties": true
+                    },
+                    "group": {
+                        "properties": { "members": { "elements": { "ref": "shape" } } }
+                    }
+                }
+            }
+        }
+    },
+    "properties": {
+        "shape": { "ref": "shape" },
+        "shapes": { "elements": { "ref": "shape" } }
+    },
+    "optionalProperties": {
+        "counts": { "elements": { "type": "int8" } }
+    }
+}"#;
+
+const TEST: &str = r#"package roundtrip
+
+import (
+	"encoding/json"
+	"reflect"
+	"testing"
+)
+
+var documents = []string{
+	`{"shape": {"kind": "circle", "radius": 1.5}, "shapes": []}`,
+	`{"shape": {"kind": "empty"}, "shapes": [{"kind": "extra", "tag": "x", "other": [true]}]}`,
+	`{"shape": {"kind": "group", "members": [{"kind": "group", "members": [{"kind": "empty"}]}]}, "shapes": []}`,
+	`{"shape": {"kind": "empty"}, "shapes": [], "counts": [1.0, 2e1, -128]}`,
+}
+
+func TestRoundTrip(t *testing.T) {
+	for _, document := range documents {
+		var root Root
+		if err := json.Unmarshal([]byte(document), &root); err != nil {
+			t.Fatalf("decoding %s: %v", document, err)
+		}
+		if err := root.Validate(); err != nil {
+			t.Fatalf("validating %s: %v", document, err)
+		}
+
+		for _, v := range []interface{}{root, &root} {
+			b, err := json.Marshal(v)
+			if err != nil {
+				t.Fatalf("encoding %s: %v", document, err)
+			}
+			assertSameJSON(t, document, string(b))
+		}
+	}
+}
+
+func TestIntegers(t *testing.T) {
+	document := `{"shape": {"kind": "empty"}, "shapes": [], "counts": [1, 128, 1.5, "1", null]}`
+	var root Root
+	if err := json.Unmarshal([]byte(document), &root); err != nil {
+		t.Fatal(err)
+	}
+	if want := []int8{1, 0, 0, 0, 0}; !reflect.DeepEqual(*root.Counts, want) {
+		t.Errorf("want %v, got %v", want, *root.Counts)
+	}
+
+	var want ValidationErrors
+	for _, i := range []string{"1", "2", "3", "4"} {
+		want = append(want, ValidationError{
+			InstancePath: "/counts/" + i,
+			SchemaPath:   "/optionalProperties/counts/elements/type",
+		})
+	}
+	if err := root.Validate(); !reflect.DeepEqual(err, want) {
+		t.Errorf("want %v, got %v", want, err)
+	}
+}
+
+func TestVariant(t *testing.T) {
+	b, err := json.Marshal(circle())
+	if err != nil {
+		t.Fatal(err)
+	}
+	assertSameJSON(t, `{"kind": "circle", "radius": 2}`, string(b))
+}
+
+func assertSameJSON(t *testing.T, want, got string) {
+	t.Helper()
+
+	var w, g interface{}
+	if err := json.Unmarshal([]byte(want), &w); err != nil {
+		t.Fatal(err)
+	}
+	if err := json.Unmarshal([]byte(got), &g); err != nil {
+		t.Fatal(err)
+	}
+	if !reflect.DeepEqual(w, g) {
+		t.Errorf("want %s, got %s", want, got)
+	}
+}
+"#;
+
+// Builds the generated code along with TEST, and runs it. These tests are
+// ignored by default, as they need go; run them with
+// `cargo test --test go_roundtrip -- --ignored`.
+fn go_test(discriminator_style: DiscriminatorStyle, circle: &str) {
+    let dir = std::env::temp_dir().join(format!(
+        "jddf-codegen-roundtrip-{}-{:?}",
+        std::process::id(),
+        discriminator_style
+    ));
+    let options = common::golang_options(|options| {
+        options.out_dir = dir.clone();
+        options.package = "roundtrip".to_owned();
+        options.preserve_additional = true;
+        options.discriminator_style = discriminator_style;
+    });
+
+    fs::create_dir_all(&dir).unwrap();
+    for (path, out) in common::generate(SCHEMA, &options) {
+        fs::write(path, out).unwrap();
+    }
+    fs::write(dir.join("go.mod"), "module roundtrip\n\ngo 1.24\n").unwrap();
+    fs::write(
+        dir.join("roundtrip_test.go"),
+        format!(
+            "{}\nfunc circle() Shape {{\n\treturn {}\n}}\n",
+            TEST, circle
+        ),
+    )
+    .unwrap();
+
+    let output = Command::new("go")
+        .arg("test")
+        .current_dir(&dir)
+        .output()
+        .expect("running go, which must be on the PATH");
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+#[ignore = "requires go 1.24 or later on the PATH"]
+fn golang_embedded_discriminators_round_trip() {
+    go_test(
+        DiscriminatorStyle::Embedded,
+        "Shape{Kind: \"circle\", ShapeCircle: ShapeCircle{Radius: 2}}",
+    );
+}
+
+#[test]
+#[ignore = "requires go 1.24 or later on the PATH"]
+fn golang_interface_discriminators_round_trip() {
+    go_test(
+        DiscriminatorStyle::Interface,
+        "Shape{Value: ShapeCircle{Radius: 2}}",
+    );
+    go_test(
+        DiscriminatorStyle::Interface,
+        "Shape{Value: &ShapeCircle{Radius: 2}}",
+    );
+}
+
+#[test]
+fn golang_variants_are_encoded_with_their_tag() {
+    let out = common::golang(SCHEMA, |options| options.preserve_additional = true);
+
+    assert!(out.contains(
+        "\tcase \"extra\":\n\t\treturn marshalVariant(\"kind\", \"extra\", &v.ShapeExtra)\n"
+    ));
+    assert!(out.contains(
+        "\tcase \"empty\":\n\t\treturn marshalVariant(\"kind\", \"empty\", &v.ShapeEmpty)\n"
+    ));
+    assert!(!out.contains("return json.Marshal(struct {"));
+
+    // The variant no longer adds the tag itself.
+    assert!(!out.contains("json.RawMessage(\"\\\"extra\\\"\")"));
+}
@@ -0,0 +1,46 @@
+mod common;
+
+fn golang(schema: &str) -> String {
+    common::golang(schema, |_| {})
+}
+
+#[test]
+fn golang_aligns_runs_of_fields() {
+    let out = golang(
+        r#"{
+            "properties": {
+                "a": { "type": "string" },
+                "bbb": { "elements": { "type": "boolean" } },
+                "c": {
+                    "metadata": { "description": "Breaks the run." },
+                    "type": "string"
+                },
+                "dd": { "type": "int8" }
+            }
+        }"#,
+    );
+
+    assert!(out.contains(
+        "type Root struct {\n\tA   string `json:\"a\"`\n\tBbb []bool `json:\"bbb\"`\n\t// Breaks the run.\n\tC  string `json:\"c\"`\n\tDd int8   `json:\"dd\"`\n"
+    ));
+}
+
+#[test]
+fn golang_writes_empty_structs_on_one_line() {
+    let out = golang(r#"{ "properties": {}, "additionalProperties": true }"#);
+    assert!(out.contains("\ntype Root struct{}\n"));
+}
+
+#[test]
+fn golang_blank_lines_are_those_gofmt_keeps() {
+    for example in &["user", "message", "gamut", "profile"] {
+        let path = format!("examples/{}/{}.jddf.json", example, example);
+        let out = golang(&std::fs::read_to_string(path).unwrap());
+
+        assert!(out.ends_with("}\n") && !out.ends_with("\n\n"));
+        assert!(!out.contains("\n\n\n"));
+        assert!(!out.contains("{\n\n"));
+        assert!(!out.contains("\n\n\t}") && !out.contains("\n\n}"));
+        assert!(out.lines().all(|line| line == line.trim_end()));
+    }
+}
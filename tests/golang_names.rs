@@ -0,0 +1,111 @@
+mod common;
+
+fn generate(package: &str, file: Option<&str>) -> Result<Vec<(String, String)>, String> {
+    let mut options = common::golang_options(|options| {
+        options.package = package.to_owned();
+        options.file = file.map(str::to_owned);
+    });
+    options.root_name = "user_profile".to_owned();
+
+    common::try_generate(r#"{ "type": "string" }"#, &options).map_err(|err| err.to_string())
+}
+
+#[test]
+fn golang_file_defaults_to_root_name() {
+    let files = generate("models", None).unwrap();
+    assert_eq!("golang/user_profile.go", files[0].0);
+    assert!(files[0].1.contains("\npackage models\n"));
+    assert!(files[0].1.contains("\ntype UserProfile = string\n"));
+}
+
+#[test]
+fn golang_file_can_be_named() {
+    let files = generate("models", Some("types.go")).unwrap();
+    assert_eq!("golang/types.go", files[0].0);
+}
+
+#[test]
+fn golang_package_must_be_an_identifier() {
+    assert!(generate("_models", None).is_ok());
+    assert!(generate("modèles", None).is_ok());
+
+    for package in &["", "_", "2d", "my-models", "type"] {
+        let err = generate(package, None).unwrap_err();
+        assert_eq!(format!("{:?} is not a valid Go package name", package), err);
+    }
+}
+
+#[test]
+fn golang_file_must_be_a_go_source_file() {
+    for file in &[
+        "types",
+        "types_test.go",
+        "_types.go",
+        ".types.go",
+        "sub/types.go",
+    ] {
+        let err = generate("models", Some(file)).unwrap_err();
+        assert_eq!(format!("{:?} is not a valid Go file name", file), err);
+    }
+}
+
+#[test]
+fn golang_helpers_must_not_collide() {
+    let schemas = [
+        (
+            "Nullable",
+            r#"{
+                "definitions": { "nullable": { "type": "string" } },
+                "optionalProperties": { "a": { "type": "string", "nullable": true } }
+            }"#,
+        ),
+        (
+            "AllFooValues",
+            r#"{
+                "definitions": { "foo": { "enum": ["a"] }, "allFooValues": {} },
+                "properties": { "a": { "ref": "foo" } }
+            }"#,
+        ),
+        (
+            "InvalidEnumValueError",
+            r#"{
+                "definitions": { "invalidEnumValueError": {} },
+                "enum": ["a"]
+            }"#,
+        ),
+        (
+            "ValidationErrors",
+            r#"{
+                "definitions": { "validationErrors": {} },
+                "properties": {}
+            }"#,
+        ),
+        (
+            "ErrUnknownVariant",
+            r#"{
+                "definitions": { "errUnknownVariant": {} },
+                "discriminator": { "tag": "t", "mapping": { "a": { "properties": {} } } }
+            }"#,
+        ),
+    ];
+
+    for (name, schema) in &schemas {
+        let err = common::try_generate(schema, &common::golang_options(|_| {})).unwrap_err();
+        assert_eq!(
+            format!(
+                "The generated Go needs a declaration named {}, which is already taken",
+                name
+            ),
+            err.to_string()
+        );
+    }
+}
+
+#[test]
+fn golang_helpers_only_take_names_when_used() {
+    let schema = r#"{
+        "definitions": { "nullable": {}, "invalidEnumValueError": {} },
+        "properties": { "a": { "ref": "nullable" } }
+    }"#;
+    assert!(common::try_generate(schema, &common::golang_options(|_| {})).is_ok());
+}
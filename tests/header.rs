@@ -0,0 +1,66 @@
+mod common;
+
+use std::path::PathBuf;
+
+const SCHEMA: &str = r#"{ "type": "string" }"#;
+
+fn typescript(eslint_disable: bool) -> String {
+    common::typescript(SCHEMA, |options| {
+        options.source = Some(PathBuf::from("schemas/root.jddf.json"));
+        options.eslint_disable = eslint_disable;
+    })
+}
+
+#[test]
+fn golang_marks_output_as_generated() {
+    let out = common::golang(SCHEMA, |options| {
+        options.source = Some(PathBuf::from("schemas/root.jddf.json"));
+    });
+    assert!(out.starts_with(&format!(
+        "// Code generated by jddf-codegen. DO NOT EDIT.\n//\n// Source: schemas/root.jddf.json\n// Version: {}\n\npackage root\n",
+        env!("CARGO_PKG_VERSION")
+    )));
+}
+
+#[test]
+fn golang_header_without_source() {
+    let out = common::golang(SCHEMA, |_| {});
+    assert!(out.starts_with("// Code generated by jddf-codegen. DO NOT EDIT.\n//\n// Version: "));
+    assert!(!out.contains("Source:"));
+}
+
+#[test]
+fn typescript_marks_output_as_generated() {
+    let out = typescript(false);
+    assert!(out.starts_with(&format!(
+        "// Code generated by jddf-codegen. DO NOT EDIT.\n//\n// Source: schemas/root.jddf.json\n// Version: {}\n\nexport type Root = string;\n",
+        env!("CARGO_PKG_VERSION")
+    )));
+    assert!(!out.contains("eslint"));
+}
+
+#[test]
+fn typescript_can_disable_eslint() {
+    let out = typescript(true);
+    assert!(out.contains(&format!(
+        "// Version: {}\n/* eslint-disable */\n\nexport type Root",
+        env!("CARGO_PKG_VERSION")
+    )));
+}
+
+#[test]
+fn golang_header_notes_the_go_version_for_nullable() {
+    let schema = r#"{ "optionalProperties": { "a": { "type": "string", "nullable": true } } }"#;
+    let out = common::golang(schema, |_| {});
+    assert!(out.contains(&format!(
+        "// Version: {}\n// Requires Go 1.24 or later.\n\npackage root\n",
+        env!("CARGO_PKG_VERSION")
+    )));
+
+    let files = common::generate(schema, &common::golang_options(|o| o.split_files = true));
+    assert!(files
+        .iter()
+        .all(|(_, out)| out.contains("// Requires Go 1.24 or later.\n")));
+
+    assert!(!common::golang(SCHEMA, |_| {}).contains("Requires Go"));
+}
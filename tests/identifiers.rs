@@ -0,0 +1,103 @@
+mod common;
+
+use jddf_codegen::Options;
+
+const SCHEMA: &str = r#"{
+    "properties": {
+        "type": { "type": "string" },
+        "default": { "type": "string" },
+        "123abc": { "type": "string" },
+        "foo-bar": { "type": "string" },
+        "class": { "enum": ["a-b", "123"] }
+    }
+}"#;
+
+#[test]
+fn typescript_quotes_keys_which_are_not_identifiers() {
+    let out = common::typescript(SCHEMA, |_| {});
+    assert!(out.contains("  \"123abc\": string;\n"));
+    assert!(out.contains("  \"foo-bar\": string;\n"));
+    assert!(out.contains("  class: RootClass;\n"));
+    assert!(out.contains("  default: string;\n"));
+    assert!(out.contains("  type: string;\n"));
+}
+
+#[test]
+fn golang_fields_are_exported_identifiers() {
+    let out = common::golang(SCHEMA, |_| {});
+    assert!(out.contains("X123Abc string    `json:\"123abc\"`"));
+    assert!(out.contains("FooBar  string    `json:\"foo-bar\"`"));
+    assert!(out.contains("Class   RootClass `json:\"class\"`"));
+    assert!(out.contains("Default string    `json:\"default\"`"));
+    assert!(out.contains("Type    string    `json:\"type\"`"));
+    assert!(out.contains("RootClass123 RootClass = \"123\""));
+    assert!(out.contains("RootClassAB RootClass = \"a-b\""));
+}
+
+#[test]
+fn properties_differing_only_in_punctuation_collide() {
+    let schema = r#"{ "properties": { "foo-bar": {}, "foo_bar": {} }}"#;
+    let err = common::try_generate(schema, &Options::new("root")).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Root.FooBar: #/properties/foo-bar, #/properties/foo_bar"));
+}
+
+#[test]
+fn properties_differing_only_in_a_leading_x_collide() {
+    let schema = r#"{ "properties": { "123": {}, "x123": {} }}"#;
+    let err = common::try_generate(schema, &Options::new("root")).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Root.X123: #/properties/123, #/properties/x123"));
+}
+
+#[test]
+fn golang_tags_a_property_named_dash_as_such() {
+    let out = common::golang(r#"{ "properties": { "-": {} }}"#, |_| {});
+    assert!(out.contains("\tX interface{} `json:\"-,\"`\n"));
+}
+
+#[test]
+fn golang_rejects_properties_a_struct_tag_cannot_name() {
+    for json in [",", "a,b", "\\\"", ""] {
+        let schema = format!(r#"{{ "properties": {{ "{}": {{}} }}}}"#, json);
+        let err = common::try_generate(&schema, &common::golang_options(|_| {})).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("which encoding/json cannot take as a struct tag's name"),
+            "{}",
+            err
+        );
+    }
+}
+
+#[test]
+fn enum_values_differing_only_in_a_leading_x_collide() {
+    let schema = r#"{ "enum": ["1", "x1"] }"#;
+    let err = common::try_generate(schema, &Options::new("root")).unwrap_err();
+    assert!(err.to_string().contains("Root.X1: # (\"1\"), # (\"x1\")"));
+}
+
+#[test]
+fn tag_values_differing_only_in_a_leading_x_collide() {
+    let schema = r#"{
+        "discriminator": {
+            "tag": "t",
+            "mapping": { "1": { "properties": {} }, "x1": { "properties": {} } }
+        }
+    }"#;
+    let err = common::try_generate(schema, &Options::new("root")).unwrap_err();
+    assert!(err.to_string().contains(
+        "Root.X1: #/discriminator/mapping/1 (\"1\"), #/discriminator/mapping/x1 (\"x1\")"
+    ));
+}
+
+#[test]
+fn properties_differing_only_in_an_escaping_suffix_collide() {
+    let schema = r#"{ "properties": { "self": {}, "self_": {} }}"#;
+    let err = common::try_generate(schema, &Options::new("root")).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Root.Self_: #/properties/self, #/properties/self_"));
+}
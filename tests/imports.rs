@@ -0,0 +1,65 @@
+mod common;
+
+fn golang(schema: &str) -> String {
+    common::golang(schema, |_| {})
+}
+
+#[test]
+fn golang_imports_nothing_for_plain_types() {
+    let out = golang(r#"{ "elements": { "type": "string" } }"#);
+    assert!(!out.contains("import"));
+    assert!(!out.contains("ErrUnknownVariant"));
+}
+
+#[test]
+fn golang_imports_only_what_is_used() {
+    let out = golang(r#"{ "values": { "type": "timestamp" } }"#);
+    assert!(out.contains("import (\n\t\"time\"\n)\n"));
+
+    let out = golang(r#"{ "properties": { "a": { "type": "string" } } }"#);
+    assert!(out.contains("import (\n\t\"encoding/json\"\n\t\"fmt\"\n\t\"strings\"\n)\n"));
+    assert!(!out.contains("ErrUnknownVariant"));
+}
+
+#[test]
+fn golang_groups_standard_library_imports_first() {
+    let out = golang(
+        r#"{
+            "discriminator": {
+                "tag": "type",
+                "mapping": {
+                    "a": {
+                        "properties": {
+                            "at": { "type": "timestamp" },
+                            "id": {
+                                "metadata": {
+                                    "goType": "uuid.UUID",
+                                    "goImport": "github.com/google/uuid"
+                                },
+                                "type": "string"
+                            }
+                        }
+                    }
+                }
+            }
+        }"#,
+    );
+
+    assert!(out.contains(
+        "import (\n\t\"encoding/json\"\n\t\"errors\"\n\t\"fmt\"\n\t\"strings\"\n\t\"time\"\n\n\t\"github.com/google/uuid\"\n)\n"
+    ));
+    assert_eq!(1, out.matches("import").count());
+}
+
+#[test]
+fn golang_omits_absent_optional_properties() {
+    let out = golang(
+        r#"{
+            "properties": { "a": { "type": "string" } },
+            "optionalProperties": { "b": { "type": "string" } }
+        }"#,
+    );
+
+    assert!(out.contains("\tA string  `json:\"a\"`\n"));
+    assert!(out.contains("\tB *string `json:\"b,omitempty\"`\n"));
+}
@@ -0,0 +1,123 @@
+mod common;
+
+fn generate(root_name: &str, schema: &str) -> Result<Vec<(String, String)>, String> {
+    let mut options = common::typescript_options(|_| {});
+    options.root_name = root_name.to_owned();
+
+    common::try_generate(schema, &options).map_err(|err| err.to_string())
+}
+
+#[test]
+fn definition_colliding_with_hoisted_property() {
+    let err = generate(
+        "user",
+        r#"{
+            "definitions": {
+                "user_details": { "properties": { "a": { "type": "string" }}}
+            },
+            "properties": {
+                "details": { "properties": { "b": { "type": "string" }}}
+            }
+        }"#,
+    )
+    .unwrap_err();
+
+    assert!(err.contains("UserDetails: #/definitions/user_details, #/properties/details"));
+}
+
+#[test]
+fn nested_properties_colliding() {
+    let err = generate(
+        "root",
+        r#"{
+            "properties": {
+                "foo": {
+                    "properties": {
+                        "bar_baz": { "enum": ["X"] }
+                    }
+                },
+                "foo_bar": {
+                    "optionalProperties": {
+                        "baz": { "enum": ["Y"] }
+                    }
+                }
+            }
+        }"#,
+    )
+    .unwrap_err();
+
+    assert!(err.contains(
+        "RootFooBarBaz: #/properties/foo/properties/bar_baz, \
+         #/properties/foo_bar/optionalProperties/baz"
+    ));
+}
+
+#[test]
+fn enum_constant_colliding_with_type() {
+    let err = generate(
+        "root",
+        r#"{
+            "definitions": {
+                "color": { "enum": ["red"] },
+                "color_red": { "properties": {} }
+            }
+        }"#,
+    )
+    .unwrap_err();
+
+    assert!(err.contains("ColorRed: #/definitions/color (\"red\"), #/definitions/color_red"));
+}
+
+#[test]
+fn discriminator_variant_colliding_with_definition() {
+    let err = generate(
+        "root",
+        r#"{
+            "definitions": {
+                "root_event_created": { "properties": {} }
+            },
+            "properties": {
+                "event": {
+                    "discriminator": {
+                        "tag": "type",
+                        "mapping": {
+                            "created": { "properties": {} }
+                        }
+                    }
+                }
+            }
+        }"#,
+    )
+    .unwrap_err();
+
+    assert!(err.contains(
+        "RootEventCreated: #/definitions/root_event_created, \
+         #/properties/event/discriminator/mapping/created"
+    ));
+}
+
+#[test]
+fn distinct_names_are_accepted() {
+    assert!(generate(
+        "user",
+        r#"{
+            "definitions": {
+                "address": { "properties": { "a": { "type": "string" }}}
+            },
+            "properties": {
+                "details": { "properties": { "b": { "type": "string" }}}
+            }
+        }"#,
+    )
+    .is_ok());
+}
+
+#[test]
+fn root_name_must_make_an_identifier() {
+    assert!(generate("my-schema_v2", r#"{ "type": "string" }"#).is_ok());
+
+    for root_name in &["", "2fa", "user.v2", "my schema"] {
+        let err = generate(root_name, r#"{ "type": "string" }"#).unwrap_err();
+        assert!(err.contains(&format!("Root name {:?}", root_name)));
+    }
+}
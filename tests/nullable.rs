@@ -0,0 +1,52 @@
+mod common;
+
+const SCHEMA: &str = r#"{
+    "definitions": {
+        "name": { "type": "string", "nullable": true }
+    },
+    "properties": {
+        "a": { "type": "string" },
+        "b": { "type": "string", "nullable": true },
+        "c": { "ref": "name" }
+    },
+    "optionalProperties": {
+        "d": { "type": "string" },
+        "e": { "type": "string", "nullable": true }
+    }
+}"#;
+
+#[test]
+fn typescript_distinguishes_null_from_absent() {
+    let out = common::typescript(SCHEMA, |_| {});
+    assert!(out.contains("export type Name = string;\n"));
+    assert!(out.contains("  a: string;\n"));
+    assert!(out.contains("  b: string | null;\n"));
+    assert!(out.contains("  c: Name | null;\n"));
+    assert!(out.contains("  d?: string;\n"));
+    assert!(out.contains("  e?: string | null;\n"));
+}
+
+#[test]
+fn golang_distinguishes_null_from_absent() {
+    let out = common::golang(SCHEMA, |_| {});
+    assert!(out.contains("\tA string           `json:\"a\"`\n"));
+    assert!(out.contains("\tB *string          `json:\"b\"`\n"));
+    assert!(out.contains("\tC *Name            `json:\"c\"`\n"));
+    assert!(out.contains("\tD *string          `json:\"d,omitempty\"`\n"));
+    assert!(out.contains("\tE Nullable[string] `json:\"e,omitzero\"`\n"));
+    assert!(out.contains("type Nullable[T any] struct {\n"));
+}
+
+#[test]
+fn rust_distinguishes_null_from_absent() {
+    let schema = r#"{
+        "optionalProperties": {
+            "a": {},
+            "b": { "type": "string", "nullable": true }
+        }
+    }"#;
+
+    let out = common::rust(schema);
+    assert!(out.contains("    #[serde(rename = \"a\", default, skip_serializing_if = \"Option::is_none\", deserialize_with = \"deserialize_some\")]\n    pub a: Option<serde_json::Value>,\n"));
+    assert!(out.contains("    #[serde(rename = \"b\", default, skip_serializing_if = \"Option::is_none\", deserialize_with = \"deserialize_some\")]\n    pub b: Option<Option<String>>,\n"));
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use jddf_codegen::Options;
+
+const SCHEMA: &str = r#"{
+    "definitions": {
+        "user": {
+            "metadata": { "typeName": "account" },
+            "properties": {
+                "id": {
+                    "metadata": {
+                        "goType": "uuid.UUID",
+                        "goImport": "github.com/google/uuid",
+                        "tsType": "Uuid"
+                    },
+                    "type": "string"
+                },
+                "karma": {
+                    "metadata": { "goType": "int64" },
+                    "type": "float64"
+                },
+                "nested": {
+                    "properties": {}
+                }
+            }
+        }
+    },
+    "properties": {
+        "author": { "ref": "user" }
+    }
+}"#;
+
+fn golang_options() -> Options {
+    common::golang_options(|_| {})
+}
+
+#[test]
+fn type_name_replaces_derived_names() {
+    let out = common::typescript(SCHEMA, |_| {});
+    assert!(out.contains("export interface Account {\n"));
+    assert!(out.contains("export interface AccountNested {\n"));
+    assert!(out.contains("  author: Account;\n"));
+    assert!(!out.contains("User"));
+}
+
+#[test]
+fn typescript_uses_ts_type() {
+    let out = common::typescript(SCHEMA, |_| {});
+    assert!(out.contains("  id: Uuid;\n"));
+    assert!(out.contains("  karma: number;\n"));
+}
+
+#[test]
+fn golang_uses_go_type_and_import() {
+    let out = common::golang(SCHEMA, |_| {});
+    assert!(out.contains("\t\"github.com/google/uuid\"\n"));
+    assert!(out.contains("\tId     uuid.UUID     `json:\"id\"`\n"));
+    assert!(out.contains("\tKarma  int64         `json:\"karma\"`\n"));
+}
+
+#[test]
+fn malformed_metadata_is_an_error() {
+    let schema = r#"{ "metadata": { "typeName": 3 } }"#;
+    let err = common::try_generate(schema, &golang_options()).unwrap_err();
+    assert_eq!("#: metadata.typeName must be a string", err.to_string());
+
+    let schema = r#"{ "metadata": { "goImport": "fmt" }, "type": "string" }"#;
+    assert!(common::try_generate(schema, &golang_options()).is_err());
+}
+
+#[test]
+fn type_names_are_checked_for_collisions() {
+    let schema = r#"{
+        "definitions": {
+            "a": { "metadata": { "typeName": "same" }, "properties": {} },
+            "b": { "metadata": { "typeName": "same" }, "properties": {} }
+        }
+    }"#;
+
+    assert!(common::try_generate(schema, &golang_options()).is_err());
+}
@@ -0,0 +1,173 @@
+mod common;
+
+use std::fs;
+use std::process::Command;
+
+// A definition which is made up only of itself.
+const TREE: &str = r#"{
+    "definitions": { "tree": { "values": { "ref": "tree" } } },
+    "properties": { "t": { "ref": "tree" } }
+}"#;
+
+// Types which hold themselves, other than among elements or values.
+const NODE: &str = r#"{
+    "definitions": {
+        "node": {
+            "optionalProperties": {
+                "next": { "ref": "node" },
+                "children": { "elements": { "ref": "node" } }
+            }
+        },
+        "expr": {
+            "discriminator": {
+                "tag": "op",
+                "mapping": {
+                    "lit": { "properties": { "value": { "type": "float64" } } },
+                    "neg": { "properties": { "arg": { "ref": "expr" } } }
+                }
+            }
+        }
+    },
+    "properties": {
+        "node": { "ref": "node" },
+        "expr": { "ref": "expr" }
+    }
+}"#;
+
+// An alias of a class which is defined after it.
+const FORWARD: &str = r#"{
+    "definitions": {
+        "a": { "elements": { "ref": "b" } },
+        "b": { "properties": { "at": { "type": "timestamp" } } }
+    },
+    "properties": { "a": { "ref": "a" } }
+}"#;
+
+// Imports the generated module, and runs a script against it.
+fn run_python(name: &str, out: &str, script: &str) {
+    let dir = std::env::temp_dir().join(format!(
+        "jddf-codegen-recursive-{}-{}",
+        std::process::id(),
+        name
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("root.py"), out).unwrap();
+
+    let output = Command::new("python3")
+        .arg("-c")
+        .arg(format!("from root import *\n{}", script))
+        .current_dir(&dir)
+        .output()
+        .expect("running python3");
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn python_recursive_aliases() {
+    let out = common::python(TREE);
+    assert!(out.contains("Tree = Dict[str, \"Tree\"]\n"));
+    assert!(out.contains("            t=data[\"t\"],\n"));
+
+    run_python(
+        "tree",
+        &out,
+        "data = {'t': {'a': {'b': {}}}}\nassert Root.from_json(data).to_json() == data",
+    );
+}
+
+#[test]
+fn python_aliases_come_after_classes() {
+    let out = common::python(FORWARD);
+    assert!(out.find("A = List[B]").unwrap() > out.find("class B:").unwrap());
+
+    run_python(
+        "forward",
+        &out,
+        "data = {'a': [{'at': '2020-01-01T00:00:00Z'}]}\nassert Root.from_json(data).a[0].at.year == 2020",
+    );
+}
+
+#[test]
+fn java_recursive_aliases() {
+    let files = common::java(TREE);
+    let file = |name: &str| &files.iter().find(|(path, _)| path == name).unwrap().1;
+    assert!(file("java/root/Root.java").contains("    private Tree t;\n"));
+
+    let tree = file("java/root/Tree.java");
+    assert!(tree.contains("    @JsonValue\n    private Map<String, Tree> value;\n"));
+    assert!(tree.contains(
+        "    @JsonCreator(mode = JsonCreator.Mode.DELEGATING)\n    public Tree(Map<String, Tree> value) {\n"
+    ));
+}
+
+#[test]
+fn csharp_recursive_aliases() {
+    let out = common::csharp(TREE);
+    assert!(out.contains("    public Tree T { get; init; } = default!;\n"));
+    assert!(out.contains(
+        "[JsonConverter(typeof(TreeConverter))]\npublic record Tree(Dictionary<string, Tree> Value);\n"
+    ));
+    assert!(out.contains(
+        "        return new Tree(JsonSerializer.Deserialize<Dictionary<string, Tree>>(ref reader, options)!);\n"
+    ));
+}
+
+#[test]
+fn rust_recursive_aliases() {
+    let out = common::rust(TREE);
+    assert!(out.contains("pub struct Tree(pub std::collections::HashMap<String, Tree>);\n"));
+    assert!(!out.contains("pub type Tree"));
+}
+
+#[test]
+fn kotlin_recursive_aliases() {
+    let out = common::kotlin(TREE);
+    assert!(
+        out.contains("@Serializable\n@JvmInline\nvalue class Tree(val value: Map<String, Tree>)\n")
+    );
+    assert!(!out.contains("typealias Tree"));
+}
+
+#[test]
+fn swift_recursive_aliases() {
+    let out = common::swift(TREE);
+    assert!(out.contains("public struct Tree: Codable {\n    public var value: [String: Tree]\n"));
+    assert!(out.contains(
+        "        value = try decoder.singleValueContainer().decode([String: Tree].self)\n"
+    ));
+    assert!(!out.contains("typealias Tree"));
+}
+
+#[test]
+fn golang_recursive_aliases() {
+    let out = common::golang(TREE, |_| {});
+    assert!(out.contains("type Tree map[string]Tree\n"));
+    assert!(out.contains("\tT Tree `json:\"t\"`\n"));
+    assert!(!out.contains("type Tree ="));
+}
+
+#[test]
+fn rust_boxes_properties_which_hold_their_type() {
+    let out = common::rust(NODE);
+    assert!(out.contains("    pub next: Option<Box<Node>>,\n"));
+    assert!(out.contains("    pub children: Option<Vec<Node>>,\n"));
+    assert!(out.contains("    pub arg: Box<Expr>,\n"));
+    assert!(out.contains("    Neg(ExprNeg),\n"));
+    assert!(out.contains("    pub node: Node,\n"));
+}
+
+#[test]
+fn swift_types_which_hold_themselves_are_stored_indirectly() {
+    let out = common::swift(NODE);
+    assert!(out.contains("public final class Node: Codable {\n"));
+    assert!(out.contains("    public init(children: [Node]?, next: Node?) {\n"));
+    assert!(out.contains("public indirect enum Expr: Codable {\n"));
+    assert!(out.contains("public struct ExprNeg: Codable {\n"));
+    assert!(out.contains("public struct Root: Codable {\n"));
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use jddf_codegen::Options;
+
+fn options(file: Option<&str>) -> Options {
+    common::golang_options(|options| {
+        options.file = file.map(str::to_owned);
+        options.split_files = true;
+    })
+}
+
+fn golang(schema: &str) -> Vec<(String, String)> {
+    common::generate(schema, &options(None))
+}
+
+#[test]
+fn golang_writes_each_definition_to_its_own_file() {
+    let files = golang(
+        r#"{
+            "definitions": {
+                "userAccount": {
+                    "properties": {
+                        "role": { "enum": ["ADMIN", "MEMBER"] }
+                    }
+                },
+                "shape": {
+                    "discriminator": {
+                        "tag": "kind",
+                        "mapping": { "circle": { "properties": {} } }
+                    }
+                }
+            },
+            "properties": {
+                "user": { "ref": "userAccount" },
+                "shape": { "ref": "shape" }
+            }
+        }"#,
+    );
+
+    let names: Vec<&str> = files.iter().map(|(path, _)| path.as_str()).collect();
+    assert_eq!(
+        vec![
+            "golang/jddf_common.go",
+            "golang/shape.go",
+            "golang/user_account.go",
+            "golang/root.go",
+        ],
+        names
+    );
+
+    // what belongs to a definition goes along with it
+    assert!(files[2].1.contains("type UserAccountRole string\n"));
+    assert!(files[1].1.contains("type ShapeCircle struct"));
+
+    let common = &files[0].1;
+    assert!(common.contains("var ErrUnknownVariant = errors.New("));
+    assert!(common.contains("type InvalidEnumValueError struct"));
+    assert!(!common.contains("type Root"));
+    assert!(files[1..]
+        .iter()
+        .all(|(_, out)| !out.contains("ErrUnknownVariant =")));
+}
+
+#[test]
+fn golang_split_files_import_only_what_they_use() {
+    for example in &["user", "message", "gamut", "profile"] {
+        let path = format!("examples/{}/{}.jddf.json", example, example);
+        for (path, out) in golang(&std::fs::read_to_string(path).unwrap()) {
+            for (import, name) in &[
+                ("encoding/json", "json"),
+                ("errors", "errors"),
+                ("fmt", "fmt"),
+                ("strings", "strings"),
+                ("time", "time"),
+            ] {
+                let imported = out.contains(&format!("\t\"{}\"\n", import));
+                let used = out.contains(&format!("{}.", name));
+                assert_eq!(used, imported, "{} in {}", import, path);
+            }
+        }
+    }
+}
+
+#[test]
+fn golang_definition_files_must_be_usable() {
+    for definition in &["main", "widget_test", "jddf_common", "$"] {
+        let schema = format!(
+            r#"{{ "definitions": {{ {:?}: {{ "type": "string" }} }}, "type": "string" }}"#,
+            definition
+        );
+        assert!(
+            common::try_generate(&schema, &options(Some("main.go"))).is_err(),
+            "{}",
+            definition
+        );
+    }
+}
+
+#[test]
+fn golang_root_file_must_not_be_the_common_one() {
+    let schema = r#"{ "type": "string" }"#;
+    assert!(common::try_generate(schema, &options(Some("jddf_common.go"))).is_err());
+}
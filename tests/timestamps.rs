@@ -0,0 +1,68 @@
+mod common;
+
+use jddf_codegen::targets::golang;
+use jddf_codegen::Options;
+
+fn options(timestamp_style: golang::TimestampStyle, split_files: bool) -> Options {
+    common::golang_options(|options| {
+        options.timestamp_style = timestamp_style;
+        options.split_files = split_files;
+    })
+}
+
+fn golang(timestamp_style: golang::TimestampStyle) -> String {
+    let schema = r#"{ "properties": { "at": { "type": "timestamp" } } }"#;
+    common::generate_one(schema, &options(timestamp_style, false))
+}
+
+#[test]
+fn golang_timestamps_default_to_time() {
+    let out = golang(golang::TimestampStyle::Time);
+    assert!(out.contains("\tAt time.Time `json:\"at\"`\n"));
+    assert!(!out.contains("type Timestamp"));
+}
+
+#[test]
+fn golang_timestamps_as_strings() {
+    let out = golang(golang::TimestampStyle::String);
+    assert!(out.contains("\tAt string `json:\"at\"`\n"));
+    assert!(!out.contains("\"time\""));
+}
+
+#[test]
+fn golang_timestamps_keep_their_text() {
+    let out = golang(golang::TimestampStyle::Wrapper);
+    assert!(out.contains("\tAt Timestamp `json:\"at\"`\n"));
+    assert!(out.contains("type Timestamp struct {\n\tTime time.Time\n\tText string\n}\n"));
+    assert!(out.contains("\tt.Time, t.Text = parsed, s\n"));
+    assert!(out.contains("\t\"time\"\n"));
+}
+
+#[test]
+fn golang_timestamp_wrapper_is_shared_between_files() {
+    let schema = r#"{
+        "definitions": { "event": { "properties": { "at": { "type": "timestamp" } } } },
+        "ref": "event"
+    }"#;
+    let files = common::generate(schema, &options(golang::TimestampStyle::Wrapper, true));
+
+    let (shared, event) = (&files[0].1, &files[1].1);
+    assert!(shared.contains("type Timestamp struct"));
+    assert!(shared.contains("\t\"time\"\n"));
+    assert!(event.contains("\tAt Timestamp `json:\"at\"`\n"));
+    assert!(!event.contains("\"time\""));
+}
+
+#[test]
+fn golang_timestamp_wrapper_must_not_collide() {
+    let schema = r#"{
+        "definitions": { "timestamp": { "type": "string" } },
+        "type": "timestamp"
+    }"#;
+
+    let wrapper = options(golang::TimestampStyle::Wrapper, false);
+    assert!(common::try_generate(schema, &wrapper).is_err());
+
+    let time = options(golang::TimestampStyle::Time, false);
+    assert!(common::try_generate(schema, &time).is_ok());
+}
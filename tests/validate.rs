@@ -0,0 +1,152 @@
+mod common;
+
+use jddf_codegen::targets::golang;
+use jddf_codegen::Options;
+
+fn options(discriminator_style: golang::DiscriminatorStyle) -> Options {
+    common::golang_options(|options| {
+        options.preserve_additional = true;
+        options.discriminator_style = discriminator_style;
+    })
+}
+
+fn golang(schema: &str) -> String {
+    common::generate_one(schema, &options(golang::DiscriminatorStyle::Embedded))
+}
+
+#[test]
+fn golang_tracks_which_required_properties_are_present() {
+    let out = golang(
+        r#"{
+            "properties": { "a/b": { "type": "string" } },
+            "optionalProperties": { "c": { "type": "string" } },
+            "additionalProperties": true
+        }"#,
+    );
+
+    assert!(out.contains("\tmissing []string\n"));
+    assert!(out.contains(
+        "\tv.missing = nil\n\tif _, ok := obj[\"a/b\"]; !ok {\n\t\tv.missing = append(v.missing, \"/properties/a~1b\")\n\t}\n"
+    ));
+    assert!(!out.contains("obj[\"c\"]"));
+    assert!(out.contains(
+        "func (v Root) validate(path string, errs *ValidationErrors) {\n\tfor _, missing := range v.missing {\n\t\t*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: missing})\n\t}\n}\n"
+    ));
+}
+
+#[test]
+fn golang_validates_nested_values() {
+    let out = golang(
+        r#"{
+            "definitions": {
+                "color": { "enum": ["RED", "GREEN"] },
+                "colors": { "elements": { "ref": "color" } }
+            },
+            "properties": {
+                "a": { "ref": "colors" },
+                "b": { "values": { "ref": "color", "nullable": true } }
+            },
+            "optionalProperties": {
+                "c": { "ref": "color" }
+            }
+        }"#,
+    );
+
+    assert!(out.contains("func (v Root) Validate() error {\n"));
+    assert!(out.contains(
+        "\tfor i, x := range v.A {\n\t\tx.validate(path+\"/a/\"+fmt.Sprint(i), errs)\n\t}\n"
+    ));
+    assert!(out.contains(
+        "\tfor k, x := range v.B {\n\t\tif x != nil {\n\t\t\tx.validate(path+\"/b/\"+pointerEscaper.Replace(k), errs)\n\t\t}\n\t}\n"
+    ));
+    assert!(out.contains("\tif v.C != nil {\n\t\tv.C.validate(path+\"/c\", errs)\n\t}\n"));
+    assert!(out.contains(
+        "\tif !v.IsValid() {\n\t\t*errs = append(*errs, ValidationError{InstancePath: path, SchemaPath: \"/definitions/color/enum\"})\n\t}\n"
+    ));
+}
+
+#[test]
+fn golang_validates_discriminator_tags() {
+    let schema = r#"{
+        "discriminator": {
+            "tag": "kind",
+            "mapping": {
+                "circle": { "properties": { "radius": { "type": "float64" } } }
+            }
+        }
+    }"#;
+
+    let out = golang(schema);
+    assert!(out.contains("\tcase \"circle\":\n\t\tv.RootCircle.validate(path, errs)\n"));
+    assert!(out.contains(
+        "ValidationError{InstancePath: path + \"/kind\", SchemaPath: \"/discriminator/mapping\"}"
+    ));
+    assert!(out.contains(
+        "ValidationError{InstancePath: path, SchemaPath: \"/discriminator/mapping/circle\" + missing}"
+    ));
+
+    let out = common::generate_one(schema, &options(golang::DiscriminatorStyle::Interface));
+    assert!(out.contains("\tcase RootCircle:\n\t\tvalue.validate(path, errs)\n"));
+    assert!(out.contains("ValidationError{InstancePath: path, SchemaPath: \"/discriminator/tag\"}"));
+}
+
+#[test]
+fn golang_validate_must_not_collide_with_a_property() {
+    let schema = r#"{ "properties": { "validate": { "type": "string" } } }"#;
+    let options = options(golang::DiscriminatorStyle::Embedded);
+    assert!(common::try_generate(schema, &options).is_err());
+}
+
+#[test]
+fn golang_checks_integers_as_structs_are_decoded() {
+    let out = golang(
+        r#"{
+            "properties": { "a": { "type": "uint8" } },
+            "optionalProperties": { "b": { "elements": { "type": "int32" } } }
+        }"#,
+    );
+
+    // The fields keep their types, but are first decoded as raw JSON.
+    assert!(out.contains("\tA uint8    `json:\"a\"`\n"));
+    assert!(out.contains(
+        "\tvar numbers struct {\n\t\talias\n\t\tA json.RawMessage    `json:\"a\"`\n\t\tB *[]json.RawMessage `json:\"b\"`\n\t}\n"
+    ));
+    assert!(
+        out.contains("func decodeInteger(b json.RawMessage, min, max float64) (int64, bool) {\n")
+    );
+
+    // A required integer which is absent is only reported as missing.
+    assert!(out.contains(
+        "\tif _, ok := obj[\"a\"]; ok {\n\t\tif n, ok := decodeInteger(numbers.A, 0, 255); ok {\n\t\t\tv.A = uint8(n)\n\t\t} else {\n\t\t\tv.invalid = append(v.invalid, ValidationError{InstancePath: \"/a\", SchemaPath: \"/properties/a/type\"})\n"
+    ));
+    assert!(out.contains(
+        "\t\t\t\t\tv.invalid = append(v.invalid, ValidationError{InstancePath: \"/b/\" + fmt.Sprint(i), SchemaPath: \"/optionalProperties/b/elements/type\"})\n"
+    ));
+    assert!(out.contains(
+        "\tfor _, invalid := range v.invalid {\n\t\t*errs = append(*errs, ValidationError{InstancePath: path + invalid.InstancePath, SchemaPath: invalid.SchemaPath})\n\t}\n"
+    ));
+}
+
+#[test]
+fn golang_integers_are_checked_against_their_definition() {
+    let out = golang(
+        r#"{
+            "definitions": { "small": { "type": "int8" } },
+            "properties": { "a": { "values": { "ref": "small" } } }
+        }"#,
+    );
+
+    assert!(out.contains("\t\tA map[string]json.RawMessage `json:\"a\"`\n"));
+    assert!(out.contains("\t\t\tv.A[k] = int8(n)\n"));
+    assert!(out.contains(
+        "ValidationError{InstancePath: \"/a/\" + pointerEscaper.Replace(k), SchemaPath: \"/definitions/small/type\"}"
+    ));
+}
+
+#[test]
+fn golang_only_structs_holding_integers_check_them() {
+    let out = golang(r#"{ "properties": { "a": { "type": "float64" } } }"#);
+    assert!(!out.contains("invalid"));
+    assert!(!out.contains("decodeInteger"));
+    assert!(!out.contains("strconv"));
+}